@@ -0,0 +1,48 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use hypr::events::{HyprctlEventRef, HyprctlEvents};
+
+// roughly what socket2 sends while dragging a window across monitors with a
+// couple of terminals retitling themselves
+const LINES: &[&str] = &[
+    "windowtitle>>55d0c0ee9a60",
+    "windowtitlev2>>55d0c0ee9a60,nvim ~/src/hypr/src/events.rs",
+    "windowtitle>>55d0c0f1b2c0",
+    "windowtitlev2>>55d0c0f1b2c0,cargo bench -p hypr",
+    "movewindow>>55d0c0ee9a60,2",
+    "movewindowv2>>55d0c0ee9a60,2,2",
+    "focusedmon>>DP-1,2",
+    "focusedmonv2>>DP-1,2",
+    "activewindow>>kitty,nvim ~/src/hypr/src/events.rs",
+    "activewindowv2>>55d0c0ee9a60",
+    "workspace>>2",
+    "workspacev2>>2,2",
+    "openwindow>>55d0c0ee9a60,2,firefox,Mozilla Firefox",
+    "togglegroup>>1,55d0c0ee9a60,55d0c0f1b2c0",
+];
+
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    group.throughput(Throughput::Elements(LINES.len() as u64));
+
+    // `decode_from_string`, i.e. `decode` plus `into_owned`: a String per field
+    group.bench_function("owned", |b| {
+        b.iter(|| {
+            for line in LINES {
+                black_box(HyprctlEvents::decode_from_string(line.to_string()).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            for line in LINES {
+                black_box(HyprctlEventRef::decode(black_box(line)).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
@@ -0,0 +1,193 @@
+// the controller and event listener without an async runtime, over std unix sockets
+// requests and replies are the very same as for the async `Controller`;
+// the typed queries not spelled out here are one `query` away
+use std::{
+    io::{self, BufRead, BufReader, Lines, Read, Write},
+    os::unix::net::UnixStream,
+    time::Duration,
+};
+
+use log::info;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    capabilities::Capabilities,
+    controller::{
+        self, decode_option,
+        dispatch::Dispatcher,
+        expect_ok,
+        invoke::{info::Info, Method},
+        ActiveWindow, ActiveWorkspace, Client, FromOption, Monitor, Version, Workspace,
+    },
+    error::{HyprError, Result},
+    events::HyprctlEvents,
+    sock::{hyprctl_socket_path, SocketTypes},
+};
+
+#[derive(Clone)]
+pub struct Controller {
+    xdg_runtime_dir: String,
+    hypr_instance_signature: String,
+    max_response_bytes: usize,
+    response_timeout: Duration,
+}
+
+impl Controller {
+    pub fn new(xdg_runtime_dir: &str, hypr_instance_signature: &str) -> Self {
+        Controller {
+            xdg_runtime_dir: xdg_runtime_dir.to_string(),
+            hypr_instance_signature: hypr_instance_signature.to_string(),
+            max_response_bytes: controller::HYPRLAND_HYPRCTL_DEFAULT_MAX_RESPONSE_BYTES,
+            response_timeout: controller::HYPRLAND_HYPRCTL_DEFAULT_RESPONSE_TIMEOUT,
+        }
+    }
+
+    pub fn instance_signature(&self) -> &str {
+        &self.hypr_instance_signature
+    }
+
+    // hard cap on a single reply; anything larger fails with `HyprError::ResponseTooLarge`
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = max_response_bytes;
+        self
+    }
+
+    // applies to every single write and read rather than to the call as a whole
+    pub fn with_response_timeout(mut self, response_timeout: Duration) -> Self {
+        self.response_timeout = response_timeout;
+        self
+    }
+
+    pub fn invoke(&self, invoke_method: Method<'_>) -> Result<String> {
+        let socket_path = hyprctl_socket_path(
+            &self.xdg_runtime_dir,
+            &self.hypr_instance_signature,
+            SocketTypes::Controller,
+        )?;
+        let mut socket =
+            UnixStream::connect(&socket_path).map_err(|source| HyprError::ConnectFailed {
+                path: socket_path,
+                source,
+            })?;
+        socket.set_read_timeout(Some(self.response_timeout))?;
+        socket.set_write_timeout(Some(self.response_timeout))?;
+
+        let write_buf = invoke_method.to_string();
+        info!(">> hyprctl {}", &write_buf);
+
+        socket.write_all(write_buf.as_bytes()).map_err(timed_out)?;
+
+        // one byte over the cap is enough to tell it's too large
+        let mut read_buf = Vec::new();
+        socket
+            .take(self.max_response_bytes as u64 + 1)
+            .read_to_end(&mut read_buf)
+            .map_err(timed_out)?;
+        if read_buf.len() > self.max_response_bytes {
+            return Err(HyprError::ResponseTooLarge {
+                max_response_bytes: self.max_response_bytes,
+            });
+        }
+
+        info!(
+            "<< hyprctl {} .. response size {}",
+            &write_buf,
+            read_buf.len()
+        );
+
+        Ok(String::from_utf8(read_buf).map_err(|e| e.utf8_error())?)
+    }
+
+    // any json `Info` query, decoded into one of the `hypr::controller` models
+    pub fn query<T: DeserializeOwned>(&self, info: Info<'_>) -> Result<T> {
+        let reply = self.invoke(Method::Info(info))?;
+
+        Ok(serde_json::from_str(reply.as_str())?)
+    }
+
+    pub fn get_workspaces(&self) -> Result<Vec<Workspace>> {
+        self.query(Info::Workspaces)
+    }
+
+    pub fn get_active_workspace(&self) -> Result<ActiveWorkspace> {
+        self.query(Info::ActiveWorkspace)
+    }
+
+    pub fn get_active_window(&self) -> Result<ActiveWindow> {
+        self.query(Info::ActiveWindow)
+    }
+
+    pub fn get_clients(&self) -> Result<Vec<Client>> {
+        self.query(Info::Clients)
+    }
+
+    pub fn get_monitors(&self) -> Result<Vec<Monitor>> {
+        self.query(Info::Monitors)
+    }
+
+    pub fn get_version(&self) -> Result<Version> {
+        self.query(Info::Version)
+    }
+
+    pub fn get_capabilities(&self) -> Result<Capabilities> {
+        Ok(Capabilities::from(&self.get_version()?))
+    }
+
+    pub fn get_option<T: FromOption>(&self, name: &str) -> Result<T> {
+        decode_option(self.invoke(Method::Info(Info::GetOption(name)))?)
+    }
+
+    pub fn dispatch(&self, dispatcher: Dispatcher<'_>) -> Result<()> {
+        expect_ok(self.invoke(Method::Dispatch(dispatcher))?)
+    }
+
+    pub fn keyword(&self, name: &str, value: &str) -> Result<()> {
+        expect_ok(self.invoke(Method::Keyword(name, value))?)
+    }
+}
+
+// std reports an expired socket timeout as one of these, depending on the platform
+fn timed_out(e: io::Error) -> HyprError {
+    match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => HyprError::Timeout,
+        _ => e.into(),
+    }
+}
+
+// the event socket; iterate it for one decoded event per line
+// unlike the async `Hypr` it doesn't reconnect: the iterator ends with hyprland
+pub struct Listener {
+    lines: Lines<BufReader<UnixStream>>,
+}
+
+impl Listener {
+    pub fn new(xdg_runtime_dir: &str, hypr_instance_signature: &str) -> Result<Self> {
+        let socket_path = hyprctl_socket_path(
+            xdg_runtime_dir,
+            hypr_instance_signature,
+            SocketTypes::Listener,
+        )?;
+        let socket =
+            UnixStream::connect(&socket_path).map_err(|source| HyprError::ConnectFailed {
+                path: socket_path,
+                source,
+            })?;
+
+        Ok(Listener {
+            lines: BufReader::new(socket).lines(),
+        })
+    }
+}
+
+impl Iterator for Listener {
+    type Item = Result<HyprctlEvents>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e.into())),
+        };
+
+        Some(HyprctlEvents::decode_from_string(line))
+    }
+}
@@ -0,0 +1,117 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::controller::Version;
+
+// first releases that shipped each feature we care about, per the release notes at
+// https://github.com/hyprwm/Hyprland/releases
+
+// v0.34.0: "added createworkspacev2, destroyworkspacev2 and moveworkspacev2 events"
+const WORKSPACE_LIFECYCLE_V2_EVENTS_SINCE: HyprlandVersion = HyprlandVersion::new(0, 34, 0);
+// v0.38.0: "added workspacev2 event"
+const WORKSPACE_V2_EVENT_SINCE: HyprlandVersion = HyprlandVersion::new(0, 38, 0);
+// v0.36.0: "hyprctl: added focusHistoryID to clients"
+const FOCUS_HISTORY_ID_SINCE: HyprlandVersion = HyprlandVersion::new(0, 36, 0);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HyprlandVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl HyprlandVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for HyprlandVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+// "0.41.2", or a `git describe` tag like "v0.41.2-12-g3d3b8a1d"
+impl FromStr for HyprlandVersion {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('v').unwrap_or(s);
+        let s = s.split_once('-').map_or(s, |(release, _)| release);
+
+        let mut parts = s.split('.').map(|part| part.parse::<u32>().map_err(|_| ()));
+        let version = HyprlandVersion::new(
+            parts.next().ok_or(())??,
+            parts.next().ok_or(())??,
+            parts.next().unwrap_or(Ok(0))?,
+        );
+
+        match parts.next() {
+            None => Ok(version),
+            Some(_) => Err(()),
+        }
+    }
+}
+
+// what the hyprland on the other end of the sockets understands
+// `Default` assumes a current release, which is also what we fall back to
+// when the version can't be told (e.g. a custom build)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    pub version: Option<HyprlandVersion>,
+
+    // workspacev2, which came several releases after the others
+    pub workspace_v2_event: bool,
+
+    // createworkspacev2, destroyworkspacev2 and moveworkspacev2
+    pub workspace_lifecycle_v2_events: bool,
+
+    // `Client::focus_history_id`
+    pub focus_history_id: bool,
+
+    // `[[BATCH]]` requests, see `Controller::batch`
+    pub batch: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            version: None,
+            workspace_v2_event: true,
+            workspace_lifecycle_v2_events: true,
+            focus_history_id: true,
+            batch: true,
+        }
+    }
+}
+
+impl Capabilities {
+    pub fn for_version(version: HyprlandVersion) -> Self {
+        Self {
+            version: Some(version),
+            workspace_v2_event: version >= WORKSPACE_V2_EVENT_SINCE,
+            workspace_lifecycle_v2_events: version >= WORKSPACE_LIFECYCLE_V2_EVENTS_SINCE,
+            focus_history_id: version >= FOCUS_HISTORY_ID_SINCE,
+
+            // older than the json version reply itself
+            batch: true,
+        }
+    }
+}
+
+impl From<&Version> for Capabilities {
+    fn from(version: &Version) -> Self {
+        let release = version.version.as_deref().unwrap_or(&version.tag);
+
+        match release.parse() {
+            Ok(release) => Capabilities::for_version(release),
+            Err(_) => Capabilities::default(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::future::Future;
+
+use crate::{
+    capabilities::Capabilities,
+    controller::{
+        dispatch::Dispatcher,
+        expect_ok,
+        invoke::{info::Info, Method},
+        ActiveWindow, ActiveWorkspace, Client, Controller, Monitor, Version, Workspace,
+    },
+    error::Result,
+};
+
+// whatever answers hyprctl requests: the socket `Controller`, or a fake in tests
+// only `invoke` is required; the typed queries are built on top of it
+pub trait HyprControl: Clone + Send + Sync + 'static {
+    fn invoke(&self, method: Method<'_>) -> impl Future<Output = Result<String>> + Send;
+
+    // `Hypr` found hyprland again after losing it, possibly under a new signature
+    fn reconnected(&self, _instance_signature: &str) {}
+
+    fn get_workspaces(&self) -> impl Future<Output = Result<Vec<Workspace>>> + Send {
+        async {
+            let workspaces = self.invoke(Method::Info(Info::Workspaces)).await?;
+            let workspaces: Vec<Workspace> = serde_json::from_str(workspaces.as_str())?;
+
+            Ok(workspaces)
+        }
+    }
+
+    fn get_active_workspace(&self) -> impl Future<Output = Result<ActiveWorkspace>> + Send {
+        async {
+            let active_workspace = self.invoke(Method::Info(Info::ActiveWorkspace)).await?;
+            let active_workspace: ActiveWorkspace = serde_json::from_str(&active_workspace)?;
+
+            Ok(active_workspace)
+        }
+    }
+
+    fn get_active_window(&self) -> impl Future<Output = Result<ActiveWindow>> + Send {
+        async {
+            let active_window = self.invoke(Method::Info(Info::ActiveWindow)).await?;
+            let active_window: ActiveWindow = serde_json::from_str(active_window.as_str())?;
+
+            Ok(active_window)
+        }
+    }
+
+    fn get_clients(&self) -> impl Future<Output = Result<Vec<Client>>> + Send {
+        async {
+            let clients = self.invoke(Method::Info(Info::Clients)).await?;
+            let clients: Vec<Client> = serde_json::from_str(clients.as_str())?;
+
+            Ok(clients)
+        }
+    }
+
+    fn get_monitors(&self) -> impl Future<Output = Result<Vec<Monitor>>> + Send {
+        async {
+            let monitors = self.invoke(Method::Info(Info::Monitors)).await?;
+            let monitors: Vec<Monitor> = serde_json::from_str(monitors.as_str())?;
+
+            Ok(monitors)
+        }
+    }
+
+    fn get_version(&self) -> impl Future<Output = Result<Version>> + Send {
+        async {
+            let version = self.invoke(Method::Info(Info::Version)).await?;
+            let version: Version = serde_json::from_str(version.as_str())?;
+
+            Ok(version)
+        }
+    }
+
+    // what the running hyprland supports, judging by its version
+    fn get_capabilities(&self) -> impl Future<Output = Result<Capabilities>> + Send {
+        async { Ok(Capabilities::from(&self.get_version().await?)) }
+    }
+
+    fn dispatch(&self, dispatcher: Dispatcher<'_>) -> impl Future<Output = Result<()>> + Send {
+        async {
+            let reply = self.invoke(Method::Dispatch(dispatcher)).await?;
+
+            expect_ok(reply)
+        }
+    }
+}
+
+impl HyprControl for Controller {
+    async fn invoke(&self, method: Method<'_>) -> Result<String> {
+        Controller::invoke(self, method).await
+    }
+
+    fn reconnected(&self, instance_signature: &str) {
+        self.set_instance_signature(instance_signature);
+    }
+}
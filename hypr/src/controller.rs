@@ -2,116 +2,356 @@ use core::str;
 
 mod activewindow;
 mod activeworkspace;
+mod animations;
+mod batch;
+mod binds;
+mod clients;
+mod configerrors;
+mod cursorpos;
+mod decorations;
+mod devices;
+pub mod dispatch;
+mod getoption;
+mod instances;
+mod keyword;
+mod layers;
+mod layouts;
+mod locked;
+mod metrics;
+mod monitors;
+mod notify;
+mod rollinglog;
+mod splash;
+mod version;
+mod workspacerules;
 mod workspaces;
 
-use crate::sock::{new_hyprctl_socket, SocketTypes};
-use log::info;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+pub use activewindow::ActiveWindow;
+pub use activeworkspace::ActiveWorkspace;
+pub use animations::{Animation, Animations, Bezier};
+pub use batch::Batch;
+pub use binds::Bind;
+pub use clients::Client;
+pub use cursorpos::CursorPos;
+pub use decorations::Decoration;
+pub use devices::{Device, Devices, Keyboard, Mouse, Tablet};
+pub use getoption::{ConfigOption, FromOption};
+pub use instances::InstanceInfo;
+pub use layers::{Layer, Layers, MonitorLayers};
+pub use metrics::CallMetrics;
+pub use monitors::Monitor;
+pub use notify::Notifier;
+pub use version::Version;
+pub use workspacerules::WorkspaceRule;
+pub use workspaces::{Workspace, WorkspaceRef};
 
-const HYPRLAND_HYPRCTL_MAX_RESPONSE_BYTES: usize = 8192;
+#[cfg(feature = "blocking")]
+pub(crate) use getoption::decode_option;
 
-pub struct Controller(String, String);
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{HyprError, Result},
+    sock::{new_hyprctl_socket, SocketTypes},
+};
+use log::{info, warn};
+use metrics::Metrics;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::Semaphore,
+};
+
+const HYPRLAND_HYPRCTL_READ_CHUNK_BYTES: usize = 8192;
+pub(crate) const HYPRLAND_HYPRCTL_DEFAULT_MAX_RESPONSE_BYTES: usize = 16 * 1024 * 1024;
+pub(crate) const HYPRLAND_HYPRCTL_DEFAULT_RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+// hyprland answers requests one at a time anyway
+const HYPRLAND_HYPRCTL_DEFAULT_MAX_CONCURRENT_CALLS: usize = 8;
+
+// clones share the instance signature, so they keep working after `Hypr` reconnects,
+// as well as the concurrency limit and the call metrics
+#[derive(Clone)]
+pub struct Controller {
+    xdg_runtime_dir: String,
+    hypr_instance_signature: Arc<RwLock<String>>,
+    max_response_bytes: usize,
+    response_timeout: Duration,
+    sockets: Arc<Semaphore>,
+    metrics: Arc<Metrics>,
+}
 
 impl Controller {
     pub async fn new(xdg_runtime_dir: &str, hypr_instance_signature: &str) -> Self {
-        Controller(
-            xdg_runtime_dir.to_string(),
-            hypr_instance_signature.to_string(),
-        )
+        Controller {
+            xdg_runtime_dir: xdg_runtime_dir.to_string(),
+            hypr_instance_signature: Arc::new(RwLock::new(hypr_instance_signature.to_string())),
+            max_response_bytes: HYPRLAND_HYPRCTL_DEFAULT_MAX_RESPONSE_BYTES,
+            response_timeout: HYPRLAND_HYPRCTL_DEFAULT_RESPONSE_TIMEOUT,
+            sockets: Arc::new(Semaphore::new(
+                HYPRLAND_HYPRCTL_DEFAULT_MAX_CONCURRENT_CALLS,
+            )),
+            metrics: Arc::default(),
+        }
+    }
+
+    // hyprland hands out a new signature on restart
+    pub(crate) fn set_instance_signature(&self, hypr_instance_signature: &str) {
+        *self.hypr_instance_signature.write().unwrap() = hypr_instance_signature.to_string();
+    }
+
+    pub fn instance_signature(&self) -> String {
+        self.hypr_instance_signature.read().unwrap().clone()
+    }
+
+    // hard cap on a single reply; anything larger fails with `HyprError::ResponseTooLarge`
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = max_response_bytes;
+        self
+    }
+
+    // default for how long a call may take end to end, waiting for a free socket included;
+    // a stalled hyprland then fails calls with `HyprError::Timeout` instead of hanging them
+    pub fn with_response_timeout(mut self, response_timeout: Duration) -> Self {
+        self.response_timeout = response_timeout;
+        self
+    }
+
+    // how many controller sockets may be open at once; further calls queue up
+    pub fn with_max_concurrent_calls(mut self, max_concurrent_calls: NonZeroUsize) -> Self {
+        self.sockets = Arc::new(Semaphore::new(max_concurrent_calls.get()));
+        self
+    }
+
+    // counters and latencies of every call made so far, by this controller and its clones
+    pub fn metrics(&self) -> CallMetrics {
+        self.metrics.snapshot()
     }
 
     // invokes hyprctl-esque controls via socket
-    // note that this is by design a synchronous call (as per the socket impl)
-    // therefore it's safe to fire & read by one line
-    pub async fn invoke<'event>(
+    // note that this is by design a synchronous call (as per the socket impl):
+    // hyprland writes the whole reply and closes the socket, so read until EOF
+    pub async fn invoke<'event>(&self, invoke_method: invoke::Method<'event>) -> Result<String> {
+        self.invoke_with_timeout(invoke_method, self.response_timeout)
+            .await
+    }
+
+    // `invoke`, with a timeout for just this call
+    pub async fn invoke_with_timeout<'event>(
         &self,
         invoke_method: invoke::Method<'event>,
-    ) -> anyhow::Result<String> {
-        let Controller(xdg_runtime_dir, hypr_instance_signature) = self;
+        timeout: Duration,
+    ) -> Result<String> {
+        let write_buf = invoke_method.to_string();
+        let started = Instant::now();
+
+        let reply = tokio::time::timeout(timeout, self.round_trip(&write_buf))
+            .await
+            .unwrap_or(Err(HyprError::Timeout));
+
+        let latency = started.elapsed();
+        self.metrics.record(latency, &reply);
+        if let Err(HyprError::Timeout) = reply {
+            warn!("hyprctl {} timed out after {:?}", &write_buf, latency);
+        }
+
+        reply
+    }
+
+    async fn round_trip(&self, write_buf: &str) -> Result<String> {
+        // the semaphore lives as long as we do
+        let _permit = self.sockets.acquire().await.unwrap();
 
         // create one time socket
         let mut socket = new_hyprctl_socket(
-            xdg_runtime_dir,
-            hypr_instance_signature,
+            &self.xdg_runtime_dir,
+            &self.instance_signature(),
             SocketTypes::Controller,
         )
-        .await
-        .unwrap();
+        .await?;
 
-        let write_buf = match invoke_method {
-            invoke::Method::Dispatch(dispatch_args) => format!("-j dispatch {}", dispatch_args),
-            invoke::Method::Notify(icon, time_ms, color, message) => {
-                format!("-j {} {} {} {}", icon, time_ms, color, message)
-            }
-            invoke::Method::DismissNotify(dismiss) => format!("-j dismissnotify {}", dismiss),
-            invoke::Method::Info(inf) => format!("j/{}", inf),
-        };
-
-        info!(">> hyprctl {}", &write_buf);
-
-        let write_as_bytes = write_buf.as_bytes();
-        let _ = socket.write_all(write_as_bytes).await.unwrap();
-
-        socket.readable().await.unwrap();
+        info!(">> hyprctl {}", write_buf);
 
-        // try read all
-        let mut read_buf = bytes::BytesMut::with_capacity(HYPRLAND_HYPRCTL_MAX_RESPONSE_BYTES);
-        let response_size = socket.read_buf(&mut read_buf).await.unwrap();
+        socket.write_all(write_buf.as_bytes()).await?;
+        let read_buf = read_to_end(&mut socket, self.max_response_bytes).await?;
 
         info!(
             "<< hyprctl {} .. response size {}",
-            &write_buf, response_size
+            write_buf,
+            read_buf.len()
         );
 
-        Ok(str::from_utf8(read_buf.as_ref()).unwrap().into())
+        Ok(str::from_utf8(read_buf.as_ref())?.into())
+    }
+}
+
+// commands that don't return data answer "ok", or a reason for refusing
+pub(crate) fn expect_ok(reply: String) -> Result<()> {
+    match reply.trim() {
+        "ok" => Ok(()),
+        rejected => Err(HyprError::HyprctlRejected {
+            reply: rejected.to_string(),
+        }),
+    }
+}
+
+// reads until the peer closes the socket, bailing out once the cap is exceeded
+async fn read_to_end<R: AsyncReadExt + Unpin>(
+    reader: &mut R,
+    max_response_bytes: usize,
+) -> Result<bytes::BytesMut> {
+    let mut read_buf = bytes::BytesMut::with_capacity(HYPRLAND_HYPRCTL_READ_CHUNK_BYTES);
+
+    loop {
+        read_buf.reserve(HYPRLAND_HYPRCTL_READ_CHUNK_BYTES);
+        if reader.read_buf(&mut read_buf).await? == 0 {
+            return Ok(read_buf);
+        }
+
+        if read_buf.len() > max_response_bytes {
+            return Err(HyprError::ResponseTooLarge { max_response_bytes });
+        }
     }
 }
 
 pub mod invoke {
+    use std::fmt;
+
     // note: not a complete list; add as you go
     pub enum Method<'invoke> {
-        Dispatch(&'invoke str),
+        Dispatch(super::dispatch::Dispatcher<'invoke>),
         Notify(
             notify::Icon,
             notify::TimeMS,
-            notify::Color<'invoke>,
+            notify::Color,
             notify::Message<'invoke>,
         ),
         DismissNotify(dismiss_notify::Dismiss),
+        Keyword(&'invoke str, &'invoke str),
         Info(info::Info<'invoke>),
+        Batch(Vec<Method<'invoke>>),
+    }
+
+    // renders the exact request written to the controller socket
+    impl fmt::Display for Method<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Method::Dispatch(dispatcher) => write!(f, "dispatch {}", dispatcher),
+                Method::Notify(icon, time_ms, color, message) => {
+                    write!(f, "notify {} {} {} {}", icon, time_ms, color, message)
+                }
+                Method::Keyword(name, value) => write!(f, "keyword {} {}", name, value),
+                Method::DismissNotify(dismiss) => write!(f, "dismissnotify {}", dismiss),
+                Method::Info(inf) => write!(f, "j/{}", inf),
+                Method::Batch(methods) => {
+                    write!(f, "[[BATCH]]")?;
+                    for (i, method) in methods.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ";")?;
+                        }
+                        write!(f, "{}", method)?;
+                    }
+                    Ok(())
+                }
+            }
+        }
     }
 
     pub mod notify {
-        use std::fmt;
+        use std::{fmt, str::FromStr, time::Duration};
 
         use strum_macros::Display;
 
-        #[derive(Display)]
-        #[strum(serialize_all = "lowercase")]
+        use crate::error::HyprError;
+
+        #[derive(Display, Clone, Copy, Debug, Default, PartialEq, Eq)]
+        #[allow(clippy::enum_variant_names)]
         pub enum Icon {
+            #[default]
+            #[strum(to_string = "-1")]
             NoIcon,
+
+            #[strum(to_string = "0")]
             Warning,
+
+            #[strum(to_string = "1")]
             Info,
+
+            #[strum(to_string = "2")]
             Hint,
+
+            #[strum(to_string = "3")]
             Error,
+
+            #[strum(to_string = "4")]
             Confused,
+
+            #[strum(to_string = "5")]
             Ok,
         }
 
-        pub struct TimeMS(u32);
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct TimeMS(pub u32);
         impl fmt::Display for TimeMS {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "{}", self.0)
             }
         }
 
-        #[derive(Display)]
-        pub enum Color<'c> {
-            #[strum(to_string = "rgb({0})")]
-            RGB(&'c str),
+        impl From<Duration> for TimeMS {
+            fn from(duration: Duration) -> Self {
+                TimeMS(duration.as_millis().try_into().unwrap_or(u32::MAX))
+            }
+        }
+
+        #[derive(Display, Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub enum Color {
+            // whatever the icon's color is
+            #[default]
+            #[strum(to_string = "0")]
+            Default,
+
+            #[strum(to_string = "rgb({0:02x}{1:02x}{2:02x})")]
+            Rgb(u8, u8, u8),
 
-            #[strum(to_string = "rgba({0})")]
-            RGBA(&'c str),
+            #[strum(to_string = "rgba({0:02x}{1:02x}{2:02x}{3:02x})")]
+            Rgba(u8, u8, u8, u8),
+        }
+
+        // accepts "#rrggbb", "#rrggbbaa" and hyprland's own "rgb(rrggbb)" / "rgba(rrggbbaa)"
+        impl FromStr for Color {
+            type Err = HyprError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let invalid = || HyprError::InvalidColor {
+                    color: s.to_string(),
+                };
+
+                let hex = s
+                    .strip_prefix("rgba(")
+                    .or_else(|| s.strip_prefix("rgb("))
+                    .and_then(|hex| hex.strip_suffix(')'))
+                    .or_else(|| s.strip_prefix('#'))
+                    .unwrap_or(s);
+                if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+                    return Err(invalid());
+                }
+
+                let channels = (0..hex.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| invalid())?;
+
+                match channels[..] {
+                    [r, g, b] => Ok(Color::Rgb(r, g, b)),
+                    [r, g, b, a] => Ok(Color::Rgba(r, g, b, a)),
+                    _ => Err(invalid()),
+                }
+            }
         }
 
         #[derive(Display)]
@@ -140,6 +380,8 @@ pub mod invoke {
     pub mod info {
         use strum_macros::Display;
 
+        use crate::controller::dispatch::WindowSelector;
+
         #[derive(Display)]
         #[strum(serialize_all = "lowercase")]
         pub enum Info<'i> {
@@ -150,11 +392,13 @@ pub mod invoke {
             WorkspaceRules,
             Clients,
             Devices,
-            Decorations(u32),
+            #[strum(to_string = "decorations {0}")]
+            Decorations(WindowSelector<'i>),
             Binds,
             ActiveWindow,
             Layers,
             Splash,
+            #[strum(to_string = "getoption {0}")]
             GetOption(&'i str),
             CursorPos,
             Animations,
@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Info;
+use crate::{control::HyprControl, error::Result};
 
-use super::{invoke::Method, Controller};
+use super::Controller;
 
 #[derive(Serialize, Deserialize)]
 pub struct ActiveWindow {
@@ -11,10 +11,7 @@ pub struct ActiveWindow {
 }
 
 impl Controller {
-    pub async fn get_active_window(&self) -> anyhow::Result<ActiveWindow> {
-        let active_window = self.invoke(Method::Info(Info::ActiveWindow)).await?;
-        let active_window: ActiveWindow = serde_json::from_str(active_window.as_str())?;
-
-        Ok(active_window)
+    pub async fn get_active_window(&self) -> Result<ActiveWindow> {
+        HyprControl::get_active_window(self).await
     }
 }
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+// hyprland replies with a pair of arrays: [animations, beziers]
+#[derive(Serialize, Deserialize)]
+#[serde(from = "(Vec<Animation>, Vec<Bezier>)")]
+pub struct Animations {
+    pub animations: Vec<Animation>,
+    pub beziers: Vec<Bezier>,
+}
+
+impl From<(Vec<Animation>, Vec<Bezier>)> for Animations {
+    fn from((animations, beziers): (Vec<Animation>, Vec<Bezier>)) -> Self {
+        Animations {
+            animations,
+            beziers,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Animation {
+    pub name: String,
+
+    // false while inherited from its parent animation
+    pub overridden: bool,
+    pub bezier: String,
+    pub enabled: bool,
+    pub speed: f64,
+    pub style: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Bezier {
+    pub name: String,
+}
+
+impl Controller {
+    pub async fn get_animations(&self) -> Result<Animations> {
+        let animations = self.invoke(Method::Info(Info::Animations)).await?;
+        let animations: Animations = serde_json::from_str(animations.as_str())?;
+
+        Ok(animations)
+    }
+}
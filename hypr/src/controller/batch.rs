@@ -0,0 +1,68 @@
+use super::{dispatch::Dispatcher, expect_ok, invoke::Method, Controller};
+use crate::error::{HyprError, Result};
+
+// queues dispatches and keywords and sends them to hyprland as one `[[BATCH]]` request,
+// so they take effect together instead of one socket round trip each
+// note: hyprland splits batches on ';', so keep it out of the arguments
+pub struct Batch<'b> {
+    controller: &'b Controller,
+    methods: Vec<Method<'b>>,
+}
+
+impl<'b> Batch<'b> {
+    pub fn dispatch(mut self, dispatcher: Dispatcher<'b>) -> Self {
+        self.methods.push(Method::Dispatch(dispatcher));
+        self
+    }
+
+    pub fn keyword(mut self, name: &'b str, value: &'b str) -> Self {
+        self.methods.push(Method::Keyword(name, value));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+
+    // one result per queued command, in the order they were added
+    pub async fn send(self) -> Result<Vec<Result<()>>> {
+        if self.methods.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let commands = self.methods.len();
+        let reply = self.controller.invoke(Method::Batch(self.methods)).await?;
+
+        split_replies(&reply, commands)
+            .map(|replies| replies.into_iter().map(expect_ok).collect())
+            .ok_or(HyprError::HyprctlRejected { reply })
+    }
+}
+
+// newer hyprland separates the replies with blank lines, older ones just glue them together
+fn split_replies(reply: &str, commands: usize) -> Option<Vec<String>> {
+    let replies: Vec<_> = reply
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|reply| !reply.is_empty())
+        .map(str::to_string)
+        .collect();
+    if replies.len() == commands {
+        return Some(replies);
+    }
+
+    (reply.trim() == "ok".repeat(commands)).then(|| vec!["ok".to_string(); commands])
+}
+
+impl Controller {
+    pub fn batch(&self) -> Batch<'_> {
+        Batch {
+            controller: self,
+            methods: Vec::new(),
+        }
+    }
+}
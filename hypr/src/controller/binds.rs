@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct Bind {
+    pub locked: bool,
+    pub mouse: bool,
+    pub release: bool,
+    pub repeat: bool,
+    pub non_consuming: bool,
+
+    // bitmask of wlr modifiers, e.g. 64 for SUPER
+    pub modmask: u32,
+
+    // empty for the global submap
+    pub submap: String,
+    pub key: String,
+    pub keycode: i32,
+    pub catch_all: bool,
+
+    // bindd only, on hyprland versions that know about it
+    #[serde(default)]
+    pub description: String,
+    pub dispatcher: String,
+    pub arg: String,
+}
+
+impl Controller {
+    pub async fn get_binds(&self) -> Result<Vec<Bind>> {
+        let binds = self.invoke(Method::Info(Info::Binds)).await?;
+        let binds: Vec<Bind> = serde_json::from_str(binds.as_str())?;
+
+        Ok(binds)
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{
+    control::HyprControl,
+    error::Result,
+    ids::{WindowAddress, WorkspaceId},
+};
+
+use super::{workspaces::WorkspaceRef, Controller};
+
+#[derive(Serialize, Deserialize)]
+pub struct Client {
+    pub address: WindowAddress,
+    pub mapped: bool,
+    pub hidden: bool,
+    pub at: [i32; 2],
+    pub size: [i32; 2],
+    pub workspace: WorkspaceRef,
+    pub floating: bool,
+    pub pseudo: bool,
+
+    // -1 while the window is not on any monitor
+    pub monitor: i32,
+    pub class: String,
+    pub title: String,
+
+    #[serde(rename = "initialClass")]
+    pub initial_class: String,
+
+    #[serde(rename = "initialTitle")]
+    pub initial_title: String,
+    pub pid: i32,
+    pub xwayland: bool,
+    pub pinned: bool,
+
+    // 0 none, 1 maximized, 2 fullscreen, 3 maximized and fullscreen;
+    // older hyprland releases report a plain bool here
+    #[serde(deserialize_with = "fullscreen_state")]
+    pub fullscreen: u8,
+    pub grouped: Vec<WindowAddress>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    // 0x0 when not swallowing anything
+    pub swallowing: WindowAddress,
+
+    // 0 is the currently focused window, -1 if never focused
+    // missing on hyprland that predates it, see `Capabilities::focus_history_id`
+    #[serde(rename = "focusHistoryID", default)]
+    pub focus_history_id: Option<i32>,
+}
+
+fn fullscreen_state<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<u8, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum FullscreenState {
+        Legacy(bool),
+        State(u8),
+    }
+
+    Ok(match FullscreenState::deserialize(deserializer)? {
+        FullscreenState::Legacy(fullscreen) => fullscreen as u8 * 2,
+        FullscreenState::State(state) => state,
+    })
+}
+
+impl Controller {
+    pub async fn get_clients(&self) -> Result<Vec<Client>> {
+        HyprControl::get_clients(self).await
+    }
+
+    pub async fn get_client_by_address(&self, address: WindowAddress) -> Result<Option<Client>> {
+        let client = self
+            .get_clients()
+            .await?
+            .into_iter()
+            .find(|client| client.address == address);
+
+        Ok(client)
+    }
+
+    pub async fn get_clients_by_class(&self, class: &str) -> Result<Vec<Client>> {
+        let clients = self
+            .get_clients()
+            .await?
+            .into_iter()
+            .filter(|client| client.class == class)
+            .collect();
+
+        Ok(clients)
+    }
+
+    pub async fn get_clients_on_workspace(&self, workspace_id: WorkspaceId) -> Result<Vec<Client>> {
+        let clients = self
+            .get_clients()
+            .await?
+            .into_iter()
+            .filter(|client| client.workspace.id == workspace_id)
+            .collect();
+
+        Ok(clients)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+impl Controller {
+    // one message per config error; empty when the config is fine
+    pub async fn get_config_errors(&self) -> Result<Vec<String>> {
+        let config_errors = self.invoke(Method::Info(Info::ConfigErrors)).await?;
+        let config_errors: Vec<String> = serde_json::from_str(config_errors.as_str())?;
+
+        // hyprland answers [""] when there is nothing to report
+        Ok(config_errors
+            .into_iter()
+            .filter(|error| !error.is_empty())
+            .collect())
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+// in global layout coordinates
+#[derive(Serialize, Deserialize)]
+pub struct CursorPos {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Controller {
+    pub async fn get_cursor_pos(&self) -> Result<CursorPos> {
+        let cursor_pos = self.invoke(Method::Info(Info::CursorPos)).await?;
+        let cursor_pos: CursorPos = serde_json::from_str(cursor_pos.as_str())?;
+
+        Ok(cursor_pos)
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::{
+    dispatch::WindowSelector,
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct Decoration {
+    #[serde(rename = "decorationName")]
+    pub decoration_name: String,
+    pub priority: i32,
+}
+
+impl Controller {
+    pub async fn get_decorations(&self, window: WindowSelector<'_>) -> Result<Vec<Decoration>> {
+        let decorations = self.invoke(Method::Info(Info::Decorations(window))).await?;
+        let decorations: Vec<Decoration> = serde_json::from_str(decorations.as_str())?;
+
+        Ok(decorations)
+    }
+}
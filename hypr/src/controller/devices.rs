@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct Devices {
+    pub mice: Vec<Mouse>,
+    pub keyboards: Vec<Keyboard>,
+    pub tablets: Vec<Tablet>,
+    pub touch: Vec<Device>,
+    pub switches: Vec<Device>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Device {
+    pub address: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Mouse {
+    pub address: String,
+    pub name: String,
+
+    #[serde(rename = "defaultSpeed")]
+    pub default_speed: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Keyboard {
+    pub address: String,
+    pub name: String,
+    pub rules: String,
+    pub model: String,
+    pub layout: String,
+    pub variant: String,
+    pub options: String,
+    pub active_keymap: String,
+
+    // the one hyprland reports layout changes for
+    pub main: bool,
+}
+
+// tablets, their pads and their tools all end up in here
+#[derive(Serialize, Deserialize)]
+pub struct Tablet {
+    pub address: String,
+
+    // pads and tools are nameless
+    #[serde(default)]
+    pub name: Option<String>,
+
+    // "tabletPad" or "tabletTool"; absent for the tablet itself
+    #[serde(rename = "type", default)]
+    pub kind: Option<String>,
+
+    // the tablet a pad is attached to
+    #[serde(rename = "belongsTo", default)]
+    pub belongs_to: Option<Device>,
+}
+
+impl Controller {
+    pub async fn get_devices(&self) -> Result<Devices> {
+        let devices = self.invoke(Method::Info(Info::Devices)).await?;
+        let devices: Devices = serde_json::from_str(devices.as_str())?;
+
+        Ok(devices)
+    }
+}
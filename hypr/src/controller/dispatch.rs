@@ -0,0 +1,201 @@
+use std::fmt;
+
+use strum_macros::Display;
+
+use super::Controller;
+use crate::{
+    control::HyprControl,
+    error::Result,
+    ids::{WindowAddress, WorkspaceId},
+};
+
+// note: not a complete list; add as you go
+// each variant renders to the exact argument string hyprctl expects after `dispatch`
+pub enum Dispatcher<'d> {
+    Exec(&'d str),
+    KillActive,
+    CloseWindow(WindowSelector<'d>),
+    Workspace(WorkspaceSelector<'d>),
+    MoveToWorkspace(WorkspaceSelector<'d>, Option<WindowSelector<'d>>),
+    MoveToWorkspaceSilent(WorkspaceSelector<'d>, Option<WindowSelector<'d>>),
+    ToggleFloating(Option<WindowSelector<'d>>),
+    Fullscreen(FullscreenMode),
+    Pseudo(Option<WindowSelector<'d>>),
+    Pin(Option<WindowSelector<'d>>),
+    MoveFocus(Direction),
+    MoveWindow(Direction),
+    SwapWindow(Direction),
+    CenterWindow,
+    ResizeActive(Resize),
+    MoveActive(Resize),
+    CycleNext,
+    CyclePrev,
+    FocusWindow(WindowSelector<'d>),
+    FocusMonitor(&'d str),
+    ToggleSpecialWorkspace(Option<&'d str>),
+    ToggleGroup,
+    Submap(&'d str),
+    Exit,
+    // anything not covered above, as (name, args), e.g. `("layoutmsg", "orientationtop")`
+    Other(&'d str, &'d str),
+}
+
+impl fmt::Display for Dispatcher<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dispatcher::Exec(command) => write!(f, "exec {}", command),
+            Dispatcher::KillActive => write!(f, "killactive"),
+            Dispatcher::CloseWindow(window) => write!(f, "closewindow {}", window),
+            Dispatcher::Workspace(workspace) => write!(f, "workspace {}", workspace),
+            Dispatcher::MoveToWorkspace(workspace, None) => {
+                write!(f, "movetoworkspace {}", workspace)
+            }
+            Dispatcher::MoveToWorkspace(workspace, Some(window)) => {
+                write!(f, "movetoworkspace {},{}", workspace, window)
+            }
+            Dispatcher::MoveToWorkspaceSilent(workspace, None) => {
+                write!(f, "movetoworkspacesilent {}", workspace)
+            }
+            Dispatcher::MoveToWorkspaceSilent(workspace, Some(window)) => {
+                write!(f, "movetoworkspacesilent {},{}", workspace, window)
+            }
+            Dispatcher::ToggleFloating(window) => with_window(f, "togglefloating", window),
+            Dispatcher::Fullscreen(mode) => write!(f, "fullscreen {}", mode),
+            Dispatcher::Pseudo(window) => with_window(f, "pseudo", window),
+            Dispatcher::Pin(window) => with_window(f, "pin", window),
+            Dispatcher::MoveFocus(direction) => write!(f, "movefocus {}", direction),
+            Dispatcher::MoveWindow(direction) => write!(f, "movewindow {}", direction),
+            Dispatcher::SwapWindow(direction) => write!(f, "swapwindow {}", direction),
+            Dispatcher::CenterWindow => write!(f, "centerwindow"),
+            Dispatcher::ResizeActive(resize) => write!(f, "resizeactive {}", resize),
+            Dispatcher::MoveActive(resize) => write!(f, "moveactive {}", resize),
+            Dispatcher::CycleNext => write!(f, "cyclenext"),
+            Dispatcher::CyclePrev => write!(f, "cyclenext prev"),
+            Dispatcher::FocusWindow(window) => write!(f, "focuswindow {}", window),
+            Dispatcher::FocusMonitor(monitor) => write!(f, "focusmonitor {}", monitor),
+            Dispatcher::ToggleSpecialWorkspace(None) => write!(f, "togglespecialworkspace"),
+            Dispatcher::ToggleSpecialWorkspace(Some(name)) => {
+                write!(f, "togglespecialworkspace {}", name)
+            }
+            Dispatcher::ToggleGroup => write!(f, "togglegroup"),
+            Dispatcher::Submap(name) => write!(f, "submap {}", name),
+            Dispatcher::Exit => write!(f, "exit"),
+            Dispatcher::Other(name, args) => write!(f, "{} {}", name, args),
+        }
+    }
+}
+
+// dispatchers that act on the active window unless told otherwise
+fn with_window(
+    f: &mut fmt::Formatter<'_>,
+    dispatcher: &str,
+    window: &Option<WindowSelector<'_>>,
+) -> fmt::Result {
+    match window {
+        Some(window) => write!(f, "{} {}", dispatcher, window),
+        None => write!(f, "{}", dispatcher),
+    }
+}
+
+pub enum WindowSelector<'w> {
+    Class(&'w str),
+    InitialClass(&'w str),
+    Title(&'w str),
+    InitialTitle(&'w str),
+    Tag(&'w str),
+    Pid(u32),
+    Address(WindowAddress),
+    ActiveWindow,
+    Floating,
+    Tiled,
+}
+
+impl fmt::Display for WindowSelector<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowSelector::Class(regex) => write!(f, "class:{}", regex),
+            WindowSelector::InitialClass(regex) => write!(f, "initialclass:{}", regex),
+            WindowSelector::Title(regex) => write!(f, "title:{}", regex),
+            WindowSelector::InitialTitle(regex) => write!(f, "initialtitle:{}", regex),
+            WindowSelector::Tag(tag) => write!(f, "tag:{}", tag),
+            WindowSelector::Pid(pid) => write!(f, "pid:{}", pid),
+            WindowSelector::Address(address) => write!(f, "address:{}", address),
+            WindowSelector::ActiveWindow => write!(f, "activewindow"),
+            WindowSelector::Floating => write!(f, "floating"),
+            WindowSelector::Tiled => write!(f, "tiled"),
+        }
+    }
+}
+
+pub enum WorkspaceSelector<'w> {
+    Id(WorkspaceId),
+    // +n / -n from the current workspace
+    Relative(i32),
+    // +n / -n among workspaces on the current monitor
+    MonitorRelative(i32),
+    // +n / -n among open workspaces, including empty ones skipped
+    OpenRelative(i32),
+    Name(&'w str),
+    Previous,
+    PreviousPerMonitor,
+    Empty,
+    Special(Option<&'w str>),
+}
+
+impl fmt::Display for WorkspaceSelector<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkspaceSelector::Id(id) => write!(f, "{}", id),
+            WorkspaceSelector::Relative(offset) => write!(f, "{:+}", offset),
+            WorkspaceSelector::MonitorRelative(offset) => write!(f, "m{:+}", offset),
+            WorkspaceSelector::OpenRelative(offset) => write!(f, "r{:+}", offset),
+            WorkspaceSelector::Name(name) => write!(f, "name:{}", name),
+            WorkspaceSelector::Previous => write!(f, "previous"),
+            WorkspaceSelector::PreviousPerMonitor => write!(f, "previous_per_monitor"),
+            WorkspaceSelector::Empty => write!(f, "empty"),
+            WorkspaceSelector::Special(None) => write!(f, "special"),
+            WorkspaceSelector::Special(Some(name)) => write!(f, "special:{}", name),
+        }
+    }
+}
+
+#[derive(Display)]
+pub enum Direction {
+    #[strum(to_string = "l")]
+    Left,
+
+    #[strum(to_string = "r")]
+    Right,
+
+    #[strum(to_string = "u")]
+    Up,
+
+    #[strum(to_string = "d")]
+    Down,
+}
+
+#[derive(Display)]
+pub enum FullscreenMode {
+    #[strum(to_string = "0")]
+    Fullscreen,
+
+    #[strum(to_string = "1")]
+    Maximize,
+}
+
+#[derive(Display)]
+pub enum Resize {
+    // delta in pixels
+    #[strum(to_string = "{0} {1}")]
+    Relative(i32, i32),
+
+    // absolute size (or position, for moveactive) in pixels
+    #[strum(to_string = "exact {0} {1}")]
+    Exact(i32, i32),
+}
+
+impl Controller {
+    pub async fn dispatch(&self, dispatcher: Dispatcher<'_>) -> Result<()> {
+        HyprControl::dispatch(self, dispatcher).await
+    }
+}
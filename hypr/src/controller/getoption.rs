@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+use crate::error::{HyprError, Result};
+
+// hyprland only fills in the field matching the option's type
+// e.g. {"option": "general:border_size", "int": 2, "set": true}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ConfigOption {
+    pub option: String,
+    pub int: Option<i64>,
+    pub float: Option<f64>,
+    pub str: Option<String>,
+
+    // gradients, vectors and other option types hyprland renders itself
+    pub custom: Option<String>,
+
+    // false while the option is still at its default
+    pub set: bool,
+}
+
+// rust types an option value can be read back as
+pub trait FromOption: Sized {
+    const EXPECTED: &'static str;
+
+    fn from_option(option: &ConfigOption) -> Option<Self>;
+}
+
+impl FromOption for ConfigOption {
+    const EXPECTED: &'static str = "any";
+
+    fn from_option(option: &ConfigOption) -> Option<Self> {
+        Some(option.clone())
+    }
+}
+
+impl FromOption for i64 {
+    const EXPECTED: &'static str = "int";
+
+    fn from_option(option: &ConfigOption) -> Option<Self> {
+        option.int
+    }
+}
+
+impl FromOption for i32 {
+    const EXPECTED: &'static str = "int";
+
+    fn from_option(option: &ConfigOption) -> Option<Self> {
+        option.int?.try_into().ok()
+    }
+}
+
+// hyprland keeps booleans as ints
+impl FromOption for bool {
+    const EXPECTED: &'static str = "int";
+
+    fn from_option(option: &ConfigOption) -> Option<Self> {
+        option.int.map(|int| int != 0)
+    }
+}
+
+impl FromOption for f64 {
+    const EXPECTED: &'static str = "float";
+
+    fn from_option(option: &ConfigOption) -> Option<Self> {
+        option.float
+    }
+}
+
+impl FromOption for String {
+    const EXPECTED: &'static str = "str";
+
+    fn from_option(option: &ConfigOption) -> Option<Self> {
+        option.str.clone().or_else(|| option.custom.clone())
+    }
+}
+
+impl Controller {
+    // e.g. `get_option::<i64>("general:border_size")`
+    pub async fn get_option<T: FromOption>(&self, name: &str) -> Result<T> {
+        let option = self.invoke(Method::Info(Info::GetOption(name))).await?;
+
+        decode_option(option)
+    }
+}
+
+// shared with the blocking controller
+pub(crate) fn decode_option<T: FromOption>(option: String) -> Result<T> {
+    // unknown options get a plain text reply instead of json
+    let option: ConfigOption = match serde_json::from_str(option.as_str()) {
+        Ok(option) => option,
+        Err(_) if !option.trim_start().starts_with('{') => {
+            return Err(HyprError::HyprctlRejected {
+                reply: option.trim().to_string(),
+            })
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    T::from_option(&option).ok_or(HyprError::UnexpectedOptionType {
+        option: option.option,
+        expected: T::EXPECTED,
+    })
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+// every hyprland running for this user, as seen by the one we ask
+#[derive(Serialize, Deserialize)]
+pub struct InstanceInfo {
+    #[serde(rename = "instance")]
+    pub signature: String,
+
+    // start time, unix seconds
+    pub time: u64,
+    pub pid: u32,
+    pub wl_socket: String,
+}
+
+impl Controller {
+    pub async fn get_instances(&self) -> Result<Vec<InstanceInfo>> {
+        let instances = self.invoke(Method::Info(Info::Instances)).await?;
+        let instances: Vec<InstanceInfo> = serde_json::from_str(instances.as_str())?;
+
+        Ok(instances)
+    }
+}
@@ -0,0 +1,11 @@
+use super::{expect_ok, invoke::Method, Controller};
+use crate::error::Result;
+
+impl Controller {
+    // sets a config option until the next reload, e.g. `keyword("general:gaps_in", "0")`
+    pub async fn keyword(&self, name: &str, value: &str) -> Result<()> {
+        let reply = self.invoke(Method::Keyword(name, value)).await?;
+
+        expect_ok(reply)
+    }
+}
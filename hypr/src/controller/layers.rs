@@ -0,0 +1,38 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Result, ids::MonitorName};
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+// layer surfaces per monitor
+pub type Layers = BTreeMap<MonitorName, MonitorLayers>;
+
+#[derive(Serialize, Deserialize)]
+pub struct MonitorLayers {
+    // keyed by level: "0" background, "1" bottom, "2" top, "3" overlay
+    pub levels: BTreeMap<String, Vec<Layer>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Layer {
+    pub address: String,
+    pub x: i32,
+    pub y: i32,
+    pub w: u32,
+    pub h: u32,
+    pub namespace: String,
+}
+
+impl Controller {
+    pub async fn get_layers(&self) -> Result<Layers> {
+        let layers = self.invoke(Method::Info(Info::Layers)).await?;
+        let layers: Layers = serde_json::from_str(layers.as_str())?;
+
+        Ok(layers)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+impl Controller {
+    // names of the available tiling layouts, e.g. "dwindle", "master"
+    pub async fn get_layouts(&self) -> Result<Vec<String>> {
+        let layouts = self.invoke(Method::Info(Info::Layouts)).await?;
+        let layouts: Vec<String> = serde_json::from_str(layouts.as_str())?;
+
+        Ok(layouts)
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+#[derive(Deserialize)]
+struct Locked {
+    locked: bool,
+}
+
+impl Controller {
+    // whether a session lock (e.g. hyprlock) is active
+    pub async fn get_locked(&self) -> Result<bool> {
+        let locked = self.invoke(Method::Info(Info::Locked)).await?;
+        let locked: Locked = serde_json::from_str(locked.as_str())?;
+
+        Ok(locked.locked)
+    }
+}
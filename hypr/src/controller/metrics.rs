@@ -0,0 +1,69 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use crate::error::{HyprError, Result};
+
+// shared by all clones of a controller
+#[derive(Default)]
+pub(crate) struct Metrics {
+    calls: AtomicU64,
+    failures: AtomicU64,
+    timeouts: AtomicU64,
+    total_latency_micros: AtomicU64,
+    max_latency_micros: AtomicU64,
+}
+
+impl Metrics {
+    pub(crate) fn record<T>(&self, latency: Duration, outcome: &Result<T>) {
+        let latency_micros = latency.as_micros().try_into().unwrap_or(u64::MAX);
+
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.total_latency_micros
+            .fetch_add(latency_micros, Ordering::Relaxed);
+        self.max_latency_micros
+            .fetch_max(latency_micros, Ordering::Relaxed);
+
+        match outcome {
+            Ok(_) => {}
+            Err(HyprError::Timeout) => {
+                self.failures.fetch_add(1, Ordering::Relaxed);
+                self.timeouts.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(_) => {
+                self.failures.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> CallMetrics {
+        CallMetrics {
+            calls: self.calls.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            total_latency: Duration::from_micros(self.total_latency_micros.load(Ordering::Relaxed)),
+            max_latency: Duration::from_micros(self.max_latency_micros.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+// latency covers the whole call, including the wait for a free socket
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallMetrics {
+    pub calls: u64,
+
+    // timeouts included
+    pub failures: u64,
+    pub timeouts: u64,
+    pub total_latency: Duration,
+    pub max_latency: Duration,
+}
+
+impl CallMetrics {
+    pub fn mean_latency(&self) -> Option<Duration> {
+        let calls = u32::try_from(self.calls).ok().filter(|calls| *calls > 0)?;
+
+        Some(self.total_latency / calls)
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{control::HyprControl, error::Result, ids::MonitorName};
+
+use super::{workspaces::WorkspaceRef, Controller};
+
+#[derive(Serialize, Deserialize)]
+pub struct Monitor {
+    pub id: i32,
+    pub name: MonitorName,
+    pub description: String,
+    pub make: String,
+    pub model: String,
+    pub serial: String,
+
+    pub width: u32,
+    pub height: u32,
+
+    #[serde(rename = "refreshRate")]
+    pub refresh_rate: f64,
+    pub x: i32,
+    pub y: i32,
+
+    #[serde(rename = "activeWorkspace")]
+    pub active_workspace: WorkspaceRef,
+
+    // id 0 and an empty name when no special workspace is shown
+    #[serde(rename = "specialWorkspace")]
+    pub special_workspace: WorkspaceRef,
+
+    // left, top, right, bottom
+    pub reserved: [i32; 4],
+    pub scale: f64,
+    pub transform: u8,
+    pub focused: bool,
+
+    #[serde(rename = "dpmsStatus")]
+    pub dpms_status: bool,
+    pub vrr: bool,
+
+    // not reported by older hyprland releases
+    #[serde(default)]
+    pub disabled: bool,
+
+    #[serde(rename = "currentFormat", default)]
+    pub current_format: String,
+
+    #[serde(rename = "mirrorOf", default)]
+    pub mirror_of: String,
+
+    // e.g. "2560x1600@165.00Hz"
+    #[serde(rename = "availableModes", default)]
+    pub available_modes: Vec<String>,
+}
+
+impl Controller {
+    pub async fn get_monitors(&self) -> Result<Vec<Monitor>> {
+        HyprControl::get_monitors(self).await
+    }
+}
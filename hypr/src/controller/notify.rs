@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use super::{
+    expect_ok,
+    invoke::{
+        dismiss_notify::Dismiss,
+        notify::{Color, Icon, Message},
+        Method,
+    },
+    Controller,
+};
+use crate::error::Result;
+
+const DEFAULT_NOTIFICATION_DURATION: Duration = Duration::from_secs(5);
+
+// hyprland-native notifications, drawn by the compositor itself
+// configure once, then `send` as many messages as you like
+#[derive(Clone)]
+pub struct Notifier<'n> {
+    controller: &'n Controller,
+    icon: Icon,
+    duration: Duration,
+    color: Color,
+    font_size: Option<u32>,
+}
+
+impl<'n> Notifier<'n> {
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    // e.g. `"#ff1ea3".parse()?`
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    // None keeps hyprland's default (misc:font_size)
+    pub fn with_font_size(mut self, font_size: Option<u32>) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    pub async fn send(&self, message: &str) -> Result<()> {
+        let message = match self.font_size {
+            Some(font_size) => Message::WithFontSize(font_size, message),
+            None => Message::Default(message),
+        };
+
+        let reply = self
+            .controller
+            .invoke(Method::Notify(
+                self.icon,
+                self.duration.into(),
+                self.color,
+                message,
+            ))
+            .await?;
+
+        expect_ok(reply)
+    }
+
+    pub async fn dismiss_all(&self) -> Result<()> {
+        self.dismiss(Dismiss::All).await
+    }
+
+    // dismisses up to `count` notifications, oldest first
+    pub async fn dismiss_recent(&self, count: u32) -> Result<()> {
+        self.dismiss(Dismiss::Recent(count)).await
+    }
+
+    async fn dismiss(&self, dismiss: Dismiss) -> Result<()> {
+        let reply = self
+            .controller
+            .invoke(Method::DismissNotify(dismiss))
+            .await?;
+
+        expect_ok(reply)
+    }
+}
+
+impl Controller {
+    pub fn notifier(&self) -> Notifier<'_> {
+        Notifier {
+            controller: self,
+            icon: Icon::default(),
+            duration: DEFAULT_NOTIFICATION_DURATION,
+            color: Color::default(),
+            font_size: None,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+impl Controller {
+    // the tail of hyprland's own log
+    pub async fn get_rolling_log(&self) -> Result<String> {
+        let rolling_log = self.invoke(Method::Info(Info::RollingLog)).await?;
+
+        // the "json" reply is `[\n"log":"..."]`, which no json parser accepts;
+        // only the string inside is valid
+        let escaped = rolling_log
+            .trim()
+            .strip_prefix('[')
+            .and_then(|log| log.strip_suffix(']'))
+            .and_then(|log| log.trim().strip_prefix("\"log\":"));
+
+        match escaped {
+            Some(escaped) => Ok(serde_json::from_str(escaped)?),
+            None => Ok(rolling_log),
+        }
+    }
+}
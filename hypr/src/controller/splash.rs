@@ -0,0 +1,15 @@
+use crate::error::Result;
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+impl Controller {
+    // the current splash text; hyprland sends it as is, json or not
+    pub async fn get_splash(&self) -> Result<String> {
+        let splash = self.invoke(Method::Info(Info::Splash)).await?;
+
+        Ok(splash.trim_end().to_string())
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{capabilities::Capabilities, control::HyprControl, error::Result};
+
+use super::Controller;
+
+#[derive(Serialize, Deserialize)]
+pub struct Version {
+    pub branch: String,
+    pub commit: String,
+
+    // release builds only; older hyprland leaves it out
+    #[serde(default)]
+    pub version: Option<String>,
+    pub dirty: bool,
+    pub commit_message: String,
+
+    #[serde(default)]
+    pub commit_date: String,
+
+    // e.g. "v0.41.2-12-g3d3b8a1d", i.e. `git describe`
+    pub tag: String,
+
+    // number of commits, as a string
+    pub commits: String,
+    pub flags: Vec<String>,
+}
+
+impl Controller {
+    pub async fn get_version(&self) -> Result<Version> {
+        HyprControl::get_version(self).await
+    }
+
+    pub async fn get_capabilities(&self) -> Result<Capabilities> {
+        HyprControl::get_capabilities(self).await
+    }
+}
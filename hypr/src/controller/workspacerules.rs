@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Result, ids::MonitorName};
+
+use super::{
+    invoke::{info::Info, Method},
+    Controller,
+};
+
+// hyprland only includes the fields a rule actually sets
+#[derive(Serialize, Deserialize)]
+pub struct WorkspaceRule {
+    #[serde(rename = "workspaceString")]
+    pub workspace_string: String,
+    pub monitor: Option<MonitorName>,
+    pub default: Option<bool>,
+    pub persistent: Option<bool>,
+
+    // top, right, bottom, left
+    #[serde(rename = "gapsIn")]
+    pub gaps_in: Option<[i32; 4]>,
+
+    #[serde(rename = "gapsOut")]
+    pub gaps_out: Option<[i32; 4]>,
+
+    #[serde(rename = "borderSize")]
+    pub border_size: Option<i32>,
+    pub border: Option<bool>,
+    pub shadow: Option<bool>,
+    pub rounding: Option<bool>,
+    pub decorate: Option<bool>,
+
+    #[serde(rename = "onCreatedEmpty")]
+    pub on_created_empty: Option<String>,
+
+    #[serde(rename = "defaultName")]
+    pub default_name: Option<String>,
+}
+
+impl Controller {
+    pub async fn get_workspace_rules(&self) -> Result<Vec<WorkspaceRule>> {
+        let workspace_rules = self.invoke(Method::Info(Info::WorkspaceRules)).await?;
+        let workspace_rules: Vec<WorkspaceRule> = serde_json::from_str(workspace_rules.as_str())?;
+
+        Ok(workspace_rules)
+    }
+}
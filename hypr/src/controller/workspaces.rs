@@ -1,33 +1,41 @@
-use crate::controller::invoke::{info::Info, Method};
+use crate::control::HyprControl;
+use crate::error::Result;
+use crate::ids::{MonitorName, WindowAddress, WorkspaceId};
 use serde::{Deserialize, Serialize};
 
 use super::Controller;
 
 #[derive(Serialize, Deserialize)]
 pub struct Workspace {
-    pub id: i32,
+    pub id: WorkspaceId,
     pub name: String,
-    pub monitor: String,
+    pub monitor: MonitorName,
 
+    // -1 while the workspace is not on any monitor
     #[serde(rename = "monitorID")]
-    pub monitor_id: u8,
+    pub monitor_id: i32,
     pub windows: u32,
 
     #[serde(rename = "hasfullscreen")]
     pub has_full_screen: bool,
 
+    // 0x0 when the workspace has no windows
     #[serde(rename = "lastwindow")]
-    pub last_window: String,
+    pub last_window: WindowAddress,
 
     #[serde(rename = "lastwindowtitle")]
     pub last_window_title: String,
 }
 
 impl Controller {
-    pub async fn get_workspaces(&self) -> anyhow::Result<Vec<Workspace>> {
-        let workspaces = self.invoke(Method::Info(Info::Workspaces)).await?;
-        let workspaces: Vec<Workspace> = serde_json::from_str(workspaces.as_str())?;
-
-        Ok(workspaces)
+    pub async fn get_workspaces(&self) -> Result<Vec<Workspace>> {
+        HyprControl::get_workspaces(self).await
     }
 }
+
+// short-form workspace reference embedded in monitors, clients etc.
+#[derive(Serialize, Deserialize)]
+pub struct WorkspaceRef {
+    pub id: WorkspaceId,
+    pub name: String,
+}
@@ -0,0 +1,65 @@
+use std::io;
+
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, HyprError>;
+
+#[derive(Error, Debug)]
+pub enum HyprError {
+    // hyprland isn't running, or the instance signature is stale
+    #[error("hyprland socket not found at {path}")]
+    SocketNotFound { path: String },
+
+    // nothing under the searched runtime dirs answers on its event socket
+    #[error("no running hyprland instance under {searched}")]
+    NoLiveInstance { searched: String },
+
+    #[error("no running hyprland instance matches {selector:?}")]
+    InstanceNotFound { selector: String },
+
+    #[error("failed to connect to hyprland socket at {path}: {source}")]
+    ConnectFailed { path: String, source: io::Error },
+
+    #[error("socket io failed: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("timed out waiting for hyprland")]
+    Timeout,
+
+    #[error("malformed event line {line:?}")]
+    MalformedEvent { line: String },
+
+    #[error("hyprctl rejected the request: {reply}")]
+    HyprctlRejected { reply: String },
+
+    #[error("option {option} does not hold a {expected} value")]
+    UnexpectedOptionType {
+        option: String,
+        expected: &'static str,
+    },
+
+    #[error("invalid color {color:?}, expected #rrggbb[aa] or rgb[a](...)")]
+    InvalidColor { color: String },
+
+    #[error("hyprctl response exceeds {max_response_bytes} bytes")]
+    ResponseTooLarge { max_response_bytes: usize },
+
+    #[error("hyprctl response is not valid utf-8: {0}")]
+    InvalidUtf8(#[from] std::str::Utf8Error),
+
+    #[error("failed to decode hyprctl response: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl HyprError {
+    // what `Hypr::next` returns for a single line it couldn't make sense of; the
+    // event socket itself is fine and the next call reads the following line
+    pub fn is_bad_event(&self) -> bool {
+        match self {
+            HyprError::MalformedEvent { .. } => true,
+            // not utf-8, e.g. a window title with stray bytes
+            HyprError::Io(e) => e.kind() == io::ErrorKind::InvalidData,
+            _ => false,
+        }
+    }
+}
@@ -1,90 +1,109 @@
-use anyhow::{anyhow, Context};
+use std::{fmt, str::FromStr};
+
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumDiscriminants;
+
+use crate::{
+    error::{HyprError, Result},
+    ids::{MonitorName, WindowAddress, WorkspaceId},
+};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, EnumDiscriminants)]
 #[serde(rename_all = "lowercase")]
+#[strum_discriminants(name(HyprctlEventKind), derive(Hash))]
 pub enum HyprctlEvents {
     Workspace {
         workspace_name: String,
     },
     WorkspaceV2 {
-        workspace_id: String,
+        workspace_id: WorkspaceId,
         workspace_name: String,
     },
     FocusedMon {
-        mon_name: String,
+        mon_name: MonitorName,
         workspace_name: String,
     },
+    FocusedMonV2 {
+        mon_name: MonitorName,
+        workspace_id: WorkspaceId,
+    },
     ActiveWindow {
         window_class: String,
         window_title: String,
     },
+    // None once nothing has focus
     ActiveWindowV2 {
-        window_address: String,
+        window_address: Option<WindowAddress>,
     },
     FullScreen(bool),
     MonitorRemoved {
-        monitor_name: String,
+        monitor_name: MonitorName,
     },
     MonitorAdded {
-        monitor_name: String,
+        monitor_name: MonitorName,
     },
     MonitorAddedV2 {
         monitor_id: String,
-        monitor_name: String,
+        monitor_name: MonitorName,
         monitor_description: String,
     },
     CreateWorkspace {
         workspace_name: String,
     },
     CreateWorkspaceV2 {
-        workspace_id: String,
+        workspace_id: WorkspaceId,
         workspace_name: String,
     },
     DestroyWorkspace {
         workspace_name: String,
     },
     DestroyWorkspaceV2 {
-        workspace_id: String,
+        workspace_id: WorkspaceId,
         workspace_name: String,
     },
     MoveWorkspace {
         workspace_name: String,
-        mon_name: String,
+        mon_name: MonitorName,
     },
     MoveWorkspaceV2 {
-        workspace_id: String,
+        workspace_id: WorkspaceId,
         workspace_name: String,
-        mon_name: String,
+        mon_name: MonitorName,
     },
     RenameWorkspace {
-        workspace_id: String,
+        workspace_id: WorkspaceId,
         new_name: String,
     },
     ActiveSpecial {
         workspace_name: String,
-        mon_name: String,
+        mon_name: MonitorName,
+    },
+    // None (with an empty name) once the special workspace is closed
+    ActiveSpecialV2 {
+        workspace_id: Option<WorkspaceId>,
+        workspace_name: String,
+        mon_name: MonitorName,
     },
     ActiveLayout {
         keyboard_name: String,
         layout_name: String,
     },
     OpenWindow {
-        window_address: String,
+        window_address: WindowAddress,
         workspace_name: String,
         window_class: String,
         window_title: String,
     },
     CloseWindow {
-        window_address: String,
+        window_address: WindowAddress,
     },
     MoveWindow {
-        window_address: String,
+        window_address: WindowAddress,
         workspace_name: String,
     },
     MoveWindowV2 {
-        window_address: String,
-        workspace_id: String,
+        window_address: WindowAddress,
+        workspace_id: WorkspaceId,
         workspace_name: String,
     },
     OpenLayer {
@@ -97,14 +116,14 @@ pub enum HyprctlEvents {
         submap_name: String,
     },
     ChangeFloatingMode {
-        window_address: String,
+        window_address: WindowAddress,
         floating: String,
     },
     Urgent {
-        window_address: String,
+        window_address: WindowAddress,
     },
     Minimize {
-        window_address: String,
+        window_address: WindowAddress,
         minimized: String,
     },
     Screencast {
@@ -112,151 +131,939 @@ pub enum HyprctlEvents {
         owner: u8,
     },
     WindowTitle {
-        window_address: String,
+        window_address: WindowAddress,
     },
     WindowTitleV2 {
-        window_address: String,
+        window_address: WindowAddress,
         window_title: String,
     },
     ToggleGroup {
         state: u8,
-        handle: Vec<String>,
+        handle: Vec<WindowAddress>,
     },
     MoveIntoGroup {
-        window_address: String,
+        window_address: WindowAddress,
     },
     MoveOutOfGroup {
-        window_address: String,
+        window_address: WindowAddress,
     },
     IgnoreGroupLock(u8),
     LockGroups(u8),
     ConfigReloaded,
     Pin {
-        window_address: String,
+        window_address: WindowAddress,
         pin_state: String,
     },
+    // window_address is empty when the bell doesn't belong to a window
+    Bell {
+        window_address: Option<WindowAddress>,
+    },
+    // synthetic, never sent by hyprland: the event socket was lost and
+    // re-established, so anything derived from earlier events may be stale
+    Reconnected,
+    // anything this crate doesn't know about yet, kept verbatim
+    Unknown {
+        name: String,
+        args: String,
+    },
 }
 
 impl HyprctlEvents {
-    pub fn decode_from_string(other: String) -> anyhow::Result<Self> {
-        let split: Vec<&str> = other.split(">>").collect();
-        let event_name = split.get(0).expect("invalid event name").to_owned();
-        let event_args: Vec<&str> = split
-            .get(1)
-            .expect("invalid event args")
-            .split(",")
-            .collect();
+    pub fn decode_from_string(other: String) -> Result<Self> {
+        HyprctlEventRef::decode(&other).map(HyprctlEventRef::into_owned)
+    }
 
-        let res = match event_name {
-            "workspace" => HyprctlEvents::Workspace {
-                workspace_name: event_args.get(0).context("no item")?.to_string(),
-            },
-            "workspacev2" => HyprctlEvents::WorkspaceV2 {
-                workspace_id: event_args.get(0).context("no item")?.to_string(),
-                workspace_name: event_args.get(1).context("no item")?.to_string(),
-            },
-            "focusedmon" => HyprctlEvents::FocusedMon {
-                mon_name: event_args.get(0).context("no item")?.to_string(),
-                workspace_name: event_args.get(1).context("no item")?.to_string(),
-            },
-            "activewindow" => HyprctlEvents::ActiveWindow {
-                window_class: event_args.get(0).context("no item")?.to_string(),
-                window_title: event_args.get(1).context("no item")?.to_string(),
-            },
-            "activewindowv2" => HyprctlEvents::ActiveWindowV2 {
-                window_address: event_args.get(0).context("no item")?.to_string(),
-            },
-            "fullscreen" => HyprctlEvents::FullScreen(
-                event_args
-                    .get(0)
-                    .context("no item")?
-                    .to_string()
-                    .parse()
-                    .context("invalid bool")?,
-            ),
-            "monitorremoved" => HyprctlEvents::MonitorRemoved {
-                monitor_name: event_args.get(0).context("no item")?.to_string(),
-            },
-            "monitoradded" => HyprctlEvents::MonitorAdded {
-                monitor_name: event_args.get(0).context("no item")?.to_string(),
-            },
-            "monitoraddedv2" => HyprctlEvents::MonitorAddedV2 {
-                monitor_id: event_args.get(0).context("no item")?.to_string(),
-                monitor_name: event_args.get(1).context("no item")?.to_string(),
-                monitor_description: event_args.get(2).context("no item")?.to_string(),
-            },
-            "createworkspace" => HyprctlEvents::CreateWorkspace {
-                workspace_name: event_args.get(0).context("no item")?.to_string(),
-            },
-            "createworkspacev2" => HyprctlEvents::CreateWorkspaceV2 {
-                workspace_id: event_args.get(0).context("no item")?.to_string(),
-                workspace_name: event_args.get(1).context("no item")?.to_string(),
-            },
-            "destroyworkspace" => HyprctlEvents::DestroyWorkspace {
-                workspace_name: event_args.get(0).context("no item")?.to_string(),
-            },
-            "destroyworkspacev2" => HyprctlEvents::DestroyWorkspaceV2 {
-                workspace_id: event_args.get(0).context("no item")?.to_string(),
-                workspace_name: event_args.get(1).context("no item")?.to_string(),
-            },
-            "moveworkspace" => HyprctlEvents::MoveWorkspace {
-                workspace_name: event_args.get(0).context("no item")?.to_string(),
-                mon_name: event_args.get(1).context("no item")?.to_string(),
-            },
-            "moveworkspacev2" => HyprctlEvents::MoveWorkspaceV2 {
-                workspace_id: event_args.get(0).context("no item")?.to_string(),
-                workspace_name: event_args.get(1).context("no item")?.to_string(),
-                mon_name: event_args.get(2).context("no item")?.to_string(),
-            },
-            "renameworkspace" => HyprctlEvents::RenameWorkspace {
-                workspace_id: event_args.get(0).context("no item")?.to_string(),
-                new_name: event_args.get(1).context("no item")?.to_string(),
-            },
-            "openlayer" => HyprctlEvents::OpenLayer {
-                namespace: event_args.get(0).context("no item")?.to_string(),
-            },
-            "closelayer" => HyprctlEvents::CloseLayer {
-                namespace: event_args.get(0).context("no item")?.to_string(),
-            },
-            "changefloatingmode" => HyprctlEvents::ChangeFloatingMode {
-                window_address: event_args.get(0).context("no item")?.to_string(),
-                floating: event_args.get(1).context("no item")?.to_string(),
-            },
-            // "togglegroup" => HyprctlEvents::ToggleGroup {
-            //     state: event_args
-            //         .get(0)
-            //         .context("no item")?
-            //         .to_string()
-            //         .parse()
-            //         .context("invalid u8")?,
-            //     handle: event_args
-            //         .get(1)
-            //         .context("no item")?
-            //         .to_string()
-            //         .split(" ")
-            //         .collect(),
-            // },
-            "ignore_grouplock" => HyprctlEvents::IgnoreGroupLock(
-                event_args
-                    .get(0)
-                    .context("no item")?
-                    .to_string()
-                    .parse()
-                    .context("invalid u8")?,
-            ),
-            "lockgroups" => HyprctlEvents::LockGroups(
-                event_args
-                    .get(0)
-                    .context("no item")?
-                    .to_string()
-                    .parse()
-                    .context("invalid u8")?,
-            ),
+    pub fn encode_to_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+// `HyprctlEvents`, borrowing its strings from the line it was decoded from
+// instead of allocating them, for hot loops (window drags, terminals retitling
+// themselves) that look at most events only to throw them away
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HyprctlEventRef<'a> {
+    Workspace {
+        workspace_name: &'a str,
+    },
+    WorkspaceV2 {
+        workspace_id: WorkspaceId,
+        workspace_name: &'a str,
+    },
+    FocusedMon {
+        mon_name: &'a str,
+        workspace_name: &'a str,
+    },
+    FocusedMonV2 {
+        mon_name: &'a str,
+        workspace_id: WorkspaceId,
+    },
+    ActiveWindow {
+        window_class: &'a str,
+        window_title: &'a str,
+    },
+    // None once nothing has focus
+    ActiveWindowV2 {
+        window_address: Option<WindowAddress>,
+    },
+    FullScreen(bool),
+    MonitorRemoved {
+        monitor_name: &'a str,
+    },
+    MonitorAdded {
+        monitor_name: &'a str,
+    },
+    MonitorAddedV2 {
+        monitor_id: &'a str,
+        monitor_name: &'a str,
+        monitor_description: &'a str,
+    },
+    CreateWorkspace {
+        workspace_name: &'a str,
+    },
+    CreateWorkspaceV2 {
+        workspace_id: WorkspaceId,
+        workspace_name: &'a str,
+    },
+    DestroyWorkspace {
+        workspace_name: &'a str,
+    },
+    DestroyWorkspaceV2 {
+        workspace_id: WorkspaceId,
+        workspace_name: &'a str,
+    },
+    MoveWorkspace {
+        workspace_name: &'a str,
+        mon_name: &'a str,
+    },
+    MoveWorkspaceV2 {
+        workspace_id: WorkspaceId,
+        workspace_name: &'a str,
+        mon_name: &'a str,
+    },
+    RenameWorkspace {
+        workspace_id: WorkspaceId,
+        new_name: &'a str,
+    },
+    ActiveSpecial {
+        workspace_name: &'a str,
+        mon_name: &'a str,
+    },
+    // None (with an empty name) once the special workspace is closed
+    ActiveSpecialV2 {
+        workspace_id: Option<WorkspaceId>,
+        workspace_name: &'a str,
+        mon_name: &'a str,
+    },
+    ActiveLayout {
+        keyboard_name: &'a str,
+        layout_name: &'a str,
+    },
+    OpenWindow {
+        window_address: WindowAddress,
+        workspace_name: &'a str,
+        window_class: &'a str,
+        window_title: &'a str,
+    },
+    CloseWindow {
+        window_address: WindowAddress,
+    },
+    MoveWindow {
+        window_address: WindowAddress,
+        workspace_name: &'a str,
+    },
+    MoveWindowV2 {
+        window_address: WindowAddress,
+        workspace_id: WorkspaceId,
+        workspace_name: &'a str,
+    },
+    OpenLayer {
+        namespace: &'a str,
+    },
+    CloseLayer {
+        namespace: &'a str,
+    },
+    Submap {
+        submap_name: &'a str,
+    },
+    ChangeFloatingMode {
+        window_address: WindowAddress,
+        floating: &'a str,
+    },
+    Urgent {
+        window_address: WindowAddress,
+    },
+    Minimize {
+        window_address: WindowAddress,
+        minimized: &'a str,
+    },
+    Screencast {
+        state: u8,
+        owner: u8,
+    },
+    WindowTitle {
+        window_address: WindowAddress,
+    },
+    WindowTitleV2 {
+        window_address: WindowAddress,
+        window_title: &'a str,
+    },
+    // the comma separated addresses as sent; checked while decoding, but only
+    // split up by `into_owned`
+    ToggleGroup {
+        state: u8,
+        handle: &'a str,
+    },
+    MoveIntoGroup {
+        window_address: WindowAddress,
+    },
+    MoveOutOfGroup {
+        window_address: WindowAddress,
+    },
+    IgnoreGroupLock(u8),
+    LockGroups(u8),
+    ConfigReloaded,
+    Pin {
+        window_address: WindowAddress,
+        pin_state: &'a str,
+    },
+    // window_address is empty when the bell doesn't belong to a window
+    Bell {
+        window_address: Option<WindowAddress>,
+    },
+    // synthetic, never sent by hyprland: the event socket was lost and
+    // re-established, so anything derived from earlier events may be stale
+    Reconnected,
+    // anything this crate doesn't know about yet, kept verbatim
+    Unknown {
+        name: &'a str,
+        args: &'a str,
+    },
+}
 
-            // add all enum variants
-            e => return Err(anyhow!("unsupported event {:?}", e)),
+impl<'a> HyprctlEventRef<'a> {
+    pub fn decode(line: &'a str) -> Result<Self> {
+        let malformed = || HyprError::MalformedEvent {
+            line: line.to_string(),
+        };
+
+        // everything after the first `>>` is the raw argument string
+        let (event_name, event_args) = line.split_once(">>").ok_or_else(malformed)?;
+
+        let res = match event_name {
+            "workspace" => HyprctlEventRef::Workspace {
+                workspace_name: event_args,
+            },
+            "workspacev2" => {
+                let [workspace_id, workspace_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::WorkspaceV2 {
+                    workspace_id: workspace_id.parse().map_err(|_| malformed())?,
+                    workspace_name,
+                }
+            }
+            "focusedmon" => {
+                let [mon_name, workspace_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::FocusedMon {
+                    mon_name,
+                    workspace_name,
+                }
+            }
+            "focusedmonv2" => {
+                let [mon_name, workspace_id] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::FocusedMonV2 {
+                    mon_name,
+                    workspace_id: workspace_id.parse().map_err(|_| malformed())?,
+                }
+            }
+            "activewindow" => {
+                let [window_class, window_title] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::ActiveWindow {
+                    window_class,
+                    window_title,
+                }
+            }
+            "activewindowv2" => HyprctlEventRef::ActiveWindowV2 {
+                window_address: optional(event_args).map_err(|_| malformed())?,
+            },
+            // sent as 0 / 1
+            "fullscreen" => HyprctlEventRef::FullScreen(match event_args {
+                "0" => false,
+                "1" => true,
+                _ => return Err(malformed()),
+            }),
+            "monitorremoved" => HyprctlEventRef::MonitorRemoved {
+                monitor_name: event_args,
+            },
+            "monitoradded" => HyprctlEventRef::MonitorAdded {
+                monitor_name: event_args,
+            },
+            "monitoraddedv2" => {
+                let [monitor_id, monitor_name, monitor_description] =
+                    fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::MonitorAddedV2 {
+                    monitor_id,
+                    monitor_name,
+                    monitor_description,
+                }
+            }
+            "createworkspace" => HyprctlEventRef::CreateWorkspace {
+                workspace_name: event_args,
+            },
+            "createworkspacev2" => {
+                let [workspace_id, workspace_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::CreateWorkspaceV2 {
+                    workspace_id: workspace_id.parse().map_err(|_| malformed())?,
+                    workspace_name,
+                }
+            }
+            "destroyworkspace" => HyprctlEventRef::DestroyWorkspace {
+                workspace_name: event_args,
+            },
+            "destroyworkspacev2" => {
+                let [workspace_id, workspace_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::DestroyWorkspaceV2 {
+                    workspace_id: workspace_id.parse().map_err(|_| malformed())?,
+                    workspace_name,
+                }
+            }
+            "moveworkspace" => {
+                let [workspace_name, mon_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::MoveWorkspace {
+                    workspace_name,
+                    mon_name,
+                }
+            }
+            "moveworkspacev2" => {
+                let [workspace_id, workspace_name, mon_name] =
+                    fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::MoveWorkspaceV2 {
+                    workspace_id: workspace_id.parse().map_err(|_| malformed())?,
+                    workspace_name,
+                    mon_name,
+                }
+            }
+            "renameworkspace" => {
+                let [workspace_id, new_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::RenameWorkspace {
+                    workspace_id: workspace_id.parse().map_err(|_| malformed())?,
+                    new_name,
+                }
+            }
+            "activespecial" => {
+                let [workspace_name, mon_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::ActiveSpecial {
+                    workspace_name,
+                    mon_name,
+                }
+            }
+            "activespecialv2" => {
+                let [workspace_id, workspace_name, mon_name] =
+                    fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::ActiveSpecialV2 {
+                    workspace_id: optional(workspace_id).map_err(|_| malformed())?,
+                    workspace_name,
+                    mon_name,
+                }
+            }
+            "activelayout" => {
+                let [keyboard_name, layout_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::ActiveLayout {
+                    keyboard_name,
+                    layout_name,
+                }
+            }
+            "openwindow" => {
+                let [window_address, workspace_name, window_class, window_title] =
+                    fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::OpenWindow {
+                    window_address: window_address.parse().map_err(|_| malformed())?,
+                    workspace_name,
+                    window_class,
+                    window_title,
+                }
+            }
+            "closewindow" => HyprctlEventRef::CloseWindow {
+                window_address: event_args.parse().map_err(|_| malformed())?,
+            },
+            "movewindow" => {
+                let [window_address, workspace_name] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::MoveWindow {
+                    window_address: window_address.parse().map_err(|_| malformed())?,
+                    workspace_name,
+                }
+            }
+            "movewindowv2" => {
+                let [window_address, workspace_id, workspace_name] =
+                    fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::MoveWindowV2 {
+                    window_address: window_address.parse().map_err(|_| malformed())?,
+                    workspace_id: workspace_id.parse().map_err(|_| malformed())?,
+                    workspace_name,
+                }
+            }
+            "openlayer" => HyprctlEventRef::OpenLayer {
+                namespace: event_args,
+            },
+            "closelayer" => HyprctlEventRef::CloseLayer {
+                namespace: event_args,
+            },
+            "submap" => HyprctlEventRef::Submap {
+                submap_name: event_args,
+            },
+            "changefloatingmode" => {
+                let [window_address, floating] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::ChangeFloatingMode {
+                    window_address: window_address.parse().map_err(|_| malformed())?,
+                    floating,
+                }
+            }
+            "urgent" => HyprctlEventRef::Urgent {
+                window_address: event_args.parse().map_err(|_| malformed())?,
+            },
+            // older hyprland releases call it `minimize`
+            "minimized" | "minimize" => {
+                let [window_address, minimized] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::Minimize {
+                    window_address: window_address.parse().map_err(|_| malformed())?,
+                    minimized,
+                }
+            }
+            "screencast" => {
+                let [state, owner] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::Screencast {
+                    state: state.parse().map_err(|_| malformed())?,
+                    owner: owner.parse().map_err(|_| malformed())?,
+                }
+            }
+            "windowtitle" => HyprctlEventRef::WindowTitle {
+                window_address: event_args.parse().map_err(|_| malformed())?,
+            },
+            "windowtitlev2" => {
+                let [window_address, window_title] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::WindowTitleV2 {
+                    window_address: window_address.parse().map_err(|_| malformed())?,
+                    window_title,
+                }
+            }
+            // togglegroup>>STATE,ADDRESS[,ADDRESS...]
+            "togglegroup" => {
+                let [state, handle] = fields(event_args).ok_or_else(malformed)?;
+                if !handles(handle).all(|a| a.parse::<WindowAddress>().is_ok()) {
+                    return Err(malformed());
+                }
+                HyprctlEventRef::ToggleGroup {
+                    state: state.parse().map_err(|_| malformed())?,
+                    handle,
+                }
+            }
+            "moveintogroup" => HyprctlEventRef::MoveIntoGroup {
+                window_address: event_args.parse().map_err(|_| malformed())?,
+            },
+            "moveoutofgroup" => HyprctlEventRef::MoveOutOfGroup {
+                window_address: event_args.parse().map_err(|_| malformed())?,
+            },
+            "ignoregrouplock" | "ignore_grouplock" => {
+                HyprctlEventRef::IgnoreGroupLock(event_args.parse().map_err(|_| malformed())?)
+            }
+            "lockgroups" => {
+                HyprctlEventRef::LockGroups(event_args.parse().map_err(|_| malformed())?)
+            }
+            "configreloaded" => HyprctlEventRef::ConfigReloaded,
+            // only ever our own encoding of the synthetic event, e.g. in a trace
+            "reconnected" => HyprctlEventRef::Reconnected,
+            "pin" => {
+                let [window_address, pin_state] = fields(event_args).ok_or_else(malformed)?;
+                HyprctlEventRef::Pin {
+                    window_address: window_address.parse().map_err(|_| malformed())?,
+                    pin_state,
+                }
+            }
+            "bell" => HyprctlEventRef::Bell {
+                window_address: optional(event_args).map_err(|_| malformed())?,
+            },
+            e => HyprctlEventRef::Unknown {
+                name: e,
+                args: event_args,
+            },
         };
 
         Ok(res)
     }
+
+    // copies the borrowed strings out so the event can outlive its line
+    pub fn into_owned(self) -> HyprctlEvents {
+        match self {
+            HyprctlEventRef::Workspace { workspace_name } => HyprctlEvents::Workspace {
+                workspace_name: workspace_name.to_string(),
+            },
+            HyprctlEventRef::WorkspaceV2 {
+                workspace_id,
+                workspace_name,
+            } => HyprctlEvents::WorkspaceV2 {
+                workspace_id,
+                workspace_name: workspace_name.to_string(),
+            },
+            HyprctlEventRef::FocusedMon {
+                mon_name,
+                workspace_name,
+            } => HyprctlEvents::FocusedMon {
+                mon_name: MonitorName::from(mon_name),
+                workspace_name: workspace_name.to_string(),
+            },
+            HyprctlEventRef::FocusedMonV2 {
+                mon_name,
+                workspace_id,
+            } => HyprctlEvents::FocusedMonV2 {
+                mon_name: MonitorName::from(mon_name),
+                workspace_id,
+            },
+            HyprctlEventRef::ActiveWindow {
+                window_class,
+                window_title,
+            } => HyprctlEvents::ActiveWindow {
+                window_class: window_class.to_string(),
+                window_title: window_title.to_string(),
+            },
+            HyprctlEventRef::ActiveWindowV2 { window_address } => {
+                HyprctlEvents::ActiveWindowV2 { window_address }
+            }
+            HyprctlEventRef::FullScreen(fullscreen) => HyprctlEvents::FullScreen(fullscreen),
+            HyprctlEventRef::MonitorRemoved { monitor_name } => HyprctlEvents::MonitorRemoved {
+                monitor_name: MonitorName::from(monitor_name),
+            },
+            HyprctlEventRef::MonitorAdded { monitor_name } => HyprctlEvents::MonitorAdded {
+                monitor_name: MonitorName::from(monitor_name),
+            },
+            HyprctlEventRef::MonitorAddedV2 {
+                monitor_id,
+                monitor_name,
+                monitor_description,
+            } => HyprctlEvents::MonitorAddedV2 {
+                monitor_id: monitor_id.to_string(),
+                monitor_name: MonitorName::from(monitor_name),
+                monitor_description: monitor_description.to_string(),
+            },
+            HyprctlEventRef::CreateWorkspace { workspace_name } => HyprctlEvents::CreateWorkspace {
+                workspace_name: workspace_name.to_string(),
+            },
+            HyprctlEventRef::CreateWorkspaceV2 {
+                workspace_id,
+                workspace_name,
+            } => HyprctlEvents::CreateWorkspaceV2 {
+                workspace_id,
+                workspace_name: workspace_name.to_string(),
+            },
+            HyprctlEventRef::DestroyWorkspace { workspace_name } => {
+                HyprctlEvents::DestroyWorkspace {
+                    workspace_name: workspace_name.to_string(),
+                }
+            }
+            HyprctlEventRef::DestroyWorkspaceV2 {
+                workspace_id,
+                workspace_name,
+            } => HyprctlEvents::DestroyWorkspaceV2 {
+                workspace_id,
+                workspace_name: workspace_name.to_string(),
+            },
+            HyprctlEventRef::MoveWorkspace {
+                workspace_name,
+                mon_name,
+            } => HyprctlEvents::MoveWorkspace {
+                workspace_name: workspace_name.to_string(),
+                mon_name: MonitorName::from(mon_name),
+            },
+            HyprctlEventRef::MoveWorkspaceV2 {
+                workspace_id,
+                workspace_name,
+                mon_name,
+            } => HyprctlEvents::MoveWorkspaceV2 {
+                workspace_id,
+                workspace_name: workspace_name.to_string(),
+                mon_name: MonitorName::from(mon_name),
+            },
+            HyprctlEventRef::RenameWorkspace {
+                workspace_id,
+                new_name,
+            } => HyprctlEvents::RenameWorkspace {
+                workspace_id,
+                new_name: new_name.to_string(),
+            },
+            HyprctlEventRef::ActiveSpecial {
+                workspace_name,
+                mon_name,
+            } => HyprctlEvents::ActiveSpecial {
+                workspace_name: workspace_name.to_string(),
+                mon_name: MonitorName::from(mon_name),
+            },
+            HyprctlEventRef::ActiveSpecialV2 {
+                workspace_id,
+                workspace_name,
+                mon_name,
+            } => HyprctlEvents::ActiveSpecialV2 {
+                workspace_id,
+                workspace_name: workspace_name.to_string(),
+                mon_name: MonitorName::from(mon_name),
+            },
+            HyprctlEventRef::ActiveLayout {
+                keyboard_name,
+                layout_name,
+            } => HyprctlEvents::ActiveLayout {
+                keyboard_name: keyboard_name.to_string(),
+                layout_name: layout_name.to_string(),
+            },
+            HyprctlEventRef::OpenWindow {
+                window_address,
+                workspace_name,
+                window_class,
+                window_title,
+            } => HyprctlEvents::OpenWindow {
+                window_address,
+                workspace_name: workspace_name.to_string(),
+                window_class: window_class.to_string(),
+                window_title: window_title.to_string(),
+            },
+            HyprctlEventRef::CloseWindow { window_address } => {
+                HyprctlEvents::CloseWindow { window_address }
+            }
+            HyprctlEventRef::MoveWindow {
+                window_address,
+                workspace_name,
+            } => HyprctlEvents::MoveWindow {
+                window_address,
+                workspace_name: workspace_name.to_string(),
+            },
+            HyprctlEventRef::MoveWindowV2 {
+                window_address,
+                workspace_id,
+                workspace_name,
+            } => HyprctlEvents::MoveWindowV2 {
+                window_address,
+                workspace_id,
+                workspace_name: workspace_name.to_string(),
+            },
+            HyprctlEventRef::OpenLayer { namespace } => HyprctlEvents::OpenLayer {
+                namespace: namespace.to_string(),
+            },
+            HyprctlEventRef::CloseLayer { namespace } => HyprctlEvents::CloseLayer {
+                namespace: namespace.to_string(),
+            },
+            HyprctlEventRef::Submap { submap_name } => HyprctlEvents::Submap {
+                submap_name: submap_name.to_string(),
+            },
+            HyprctlEventRef::ChangeFloatingMode {
+                window_address,
+                floating,
+            } => HyprctlEvents::ChangeFloatingMode {
+                window_address,
+                floating: floating.to_string(),
+            },
+            HyprctlEventRef::Urgent { window_address } => HyprctlEvents::Urgent { window_address },
+            HyprctlEventRef::Minimize {
+                window_address,
+                minimized,
+            } => HyprctlEvents::Minimize {
+                window_address,
+                minimized: minimized.to_string(),
+            },
+            HyprctlEventRef::Screencast { state, owner } => {
+                HyprctlEvents::Screencast { state, owner }
+            }
+            HyprctlEventRef::WindowTitle { window_address } => {
+                HyprctlEvents::WindowTitle { window_address }
+            }
+            HyprctlEventRef::WindowTitleV2 {
+                window_address,
+                window_title,
+            } => HyprctlEvents::WindowTitleV2 {
+                window_address,
+                window_title: window_title.to_string(),
+            },
+            HyprctlEventRef::ToggleGroup { state, handle } => HyprctlEvents::ToggleGroup {
+                state,
+                handle: handles(handle).filter_map(|a| a.parse().ok()).collect(),
+            },
+            HyprctlEventRef::MoveIntoGroup { window_address } => {
+                HyprctlEvents::MoveIntoGroup { window_address }
+            }
+            HyprctlEventRef::MoveOutOfGroup { window_address } => {
+                HyprctlEvents::MoveOutOfGroup { window_address }
+            }
+            HyprctlEventRef::IgnoreGroupLock(state) => HyprctlEvents::IgnoreGroupLock(state),
+            HyprctlEventRef::LockGroups(state) => HyprctlEvents::LockGroups(state),
+            HyprctlEventRef::ConfigReloaded => HyprctlEvents::ConfigReloaded,
+            HyprctlEventRef::Pin {
+                window_address,
+                pin_state,
+            } => HyprctlEvents::Pin {
+                window_address,
+                pin_state: pin_state.to_string(),
+            },
+            HyprctlEventRef::Bell { window_address } => HyprctlEvents::Bell { window_address },
+            HyprctlEventRef::Reconnected => HyprctlEvents::Reconnected,
+            HyprctlEventRef::Unknown { name, args } => HyprctlEvents::Unknown {
+                name: name.to_string(),
+                args: args.to_string(),
+            },
+        }
+    }
+}
+
+impl From<HyprctlEventRef<'_>> for HyprctlEvents {
+    fn from(event: HyprctlEventRef<'_>) -> Self {
+        event.into_owned()
+    }
+}
+
+// the exact socket2 line (minus the trailing newline) hyprland would have sent,
+// so that `decode_from_string(event.to_string()) == event`
+impl fmt::Display for HyprctlEvents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HyprctlEvents::Workspace { workspace_name } => {
+                write!(f, "workspace>>{}", workspace_name)
+            }
+            HyprctlEvents::WorkspaceV2 {
+                workspace_id,
+                workspace_name,
+            } => {
+                write!(f, "workspacev2>>{},{}", workspace_id, workspace_name)
+            }
+            HyprctlEvents::FocusedMon {
+                mon_name,
+                workspace_name,
+            } => {
+                write!(f, "focusedmon>>{},{}", mon_name, workspace_name)
+            }
+            HyprctlEvents::FocusedMonV2 {
+                mon_name,
+                workspace_id,
+            } => {
+                write!(f, "focusedmonv2>>{},{}", mon_name, workspace_id)
+            }
+            HyprctlEvents::ActiveWindow {
+                window_class,
+                window_title,
+            } => {
+                write!(f, "activewindow>>{},{}", window_class, window_title)
+            }
+            HyprctlEvents::ActiveWindowV2 { window_address } => {
+                write!(f, "activewindowv2>>{}", optional_hex(window_address))
+            }
+            HyprctlEvents::FullScreen(fullscreen) => {
+                write!(f, "fullscreen>>{}", *fullscreen as u8)
+            }
+            HyprctlEvents::MonitorRemoved { monitor_name } => {
+                write!(f, "monitorremoved>>{}", monitor_name)
+            }
+            HyprctlEvents::MonitorAdded { monitor_name } => {
+                write!(f, "monitoradded>>{}", monitor_name)
+            }
+            HyprctlEvents::MonitorAddedV2 {
+                monitor_id,
+                monitor_name,
+                monitor_description,
+            } => {
+                write!(
+                    f,
+                    "monitoraddedv2>>{},{},{}",
+                    monitor_id, monitor_name, monitor_description
+                )
+            }
+            HyprctlEvents::CreateWorkspace { workspace_name } => {
+                write!(f, "createworkspace>>{}", workspace_name)
+            }
+            HyprctlEvents::CreateWorkspaceV2 {
+                workspace_id,
+                workspace_name,
+            } => {
+                write!(f, "createworkspacev2>>{},{}", workspace_id, workspace_name)
+            }
+            HyprctlEvents::DestroyWorkspace { workspace_name } => {
+                write!(f, "destroyworkspace>>{}", workspace_name)
+            }
+            HyprctlEvents::DestroyWorkspaceV2 {
+                workspace_id,
+                workspace_name,
+            } => {
+                write!(f, "destroyworkspacev2>>{},{}", workspace_id, workspace_name)
+            }
+            HyprctlEvents::MoveWorkspace {
+                workspace_name,
+                mon_name,
+            } => {
+                write!(f, "moveworkspace>>{},{}", workspace_name, mon_name)
+            }
+            HyprctlEvents::MoveWorkspaceV2 {
+                workspace_id,
+                workspace_name,
+                mon_name,
+            } => {
+                write!(
+                    f,
+                    "moveworkspacev2>>{},{},{}",
+                    workspace_id, workspace_name, mon_name
+                )
+            }
+            HyprctlEvents::RenameWorkspace {
+                workspace_id,
+                new_name,
+            } => {
+                write!(f, "renameworkspace>>{},{}", workspace_id, new_name)
+            }
+            HyprctlEvents::ActiveSpecial {
+                workspace_name,
+                mon_name,
+            } => {
+                write!(f, "activespecial>>{},{}", workspace_name, mon_name)
+            }
+            HyprctlEvents::ActiveSpecialV2 {
+                workspace_id,
+                workspace_name,
+                mon_name,
+            } => {
+                write!(
+                    f,
+                    "activespecialv2>>{},{},{}",
+                    optional_display(workspace_id),
+                    workspace_name,
+                    mon_name
+                )
+            }
+            HyprctlEvents::ActiveLayout {
+                keyboard_name,
+                layout_name,
+            } => {
+                write!(f, "activelayout>>{},{}", keyboard_name, layout_name)
+            }
+            HyprctlEvents::OpenWindow {
+                window_address,
+                workspace_name,
+                window_class,
+                window_title,
+            } => {
+                write!(
+                    f,
+                    "openwindow>>{:x},{},{},{}",
+                    window_address, workspace_name, window_class, window_title
+                )
+            }
+            HyprctlEvents::CloseWindow { window_address } => {
+                write!(f, "closewindow>>{:x}", window_address)
+            }
+            HyprctlEvents::MoveWindow {
+                window_address,
+                workspace_name,
+            } => {
+                write!(f, "movewindow>>{:x},{}", window_address, workspace_name)
+            }
+            HyprctlEvents::MoveWindowV2 {
+                window_address,
+                workspace_id,
+                workspace_name,
+            } => {
+                write!(
+                    f,
+                    "movewindowv2>>{:x},{},{}",
+                    window_address, workspace_id, workspace_name
+                )
+            }
+            HyprctlEvents::OpenLayer { namespace } => {
+                write!(f, "openlayer>>{}", namespace)
+            }
+            HyprctlEvents::CloseLayer { namespace } => {
+                write!(f, "closelayer>>{}", namespace)
+            }
+            HyprctlEvents::Submap { submap_name } => {
+                write!(f, "submap>>{}", submap_name)
+            }
+            HyprctlEvents::ChangeFloatingMode {
+                window_address,
+                floating,
+            } => {
+                write!(f, "changefloatingmode>>{:x},{}", window_address, floating)
+            }
+            HyprctlEvents::Urgent { window_address } => {
+                write!(f, "urgent>>{:x}", window_address)
+            }
+            HyprctlEvents::Minimize {
+                window_address,
+                minimized,
+            } => {
+                write!(f, "minimized>>{:x},{}", window_address, minimized)
+            }
+            HyprctlEvents::Screencast { state, owner } => {
+                write!(f, "screencast>>{},{}", state, owner)
+            }
+            HyprctlEvents::WindowTitle { window_address } => {
+                write!(f, "windowtitle>>{:x}", window_address)
+            }
+            HyprctlEvents::WindowTitleV2 {
+                window_address,
+                window_title,
+            } => {
+                write!(f, "windowtitlev2>>{:x},{}", window_address, window_title)
+            }
+            HyprctlEvents::ToggleGroup { state, handle } => {
+                let handle: Vec<String> = handle.iter().map(|a| format!("{:x}", a)).collect();
+                write!(f, "togglegroup>>{},{}", state, handle.join(","))
+            }
+            HyprctlEvents::MoveIntoGroup { window_address } => {
+                write!(f, "moveintogroup>>{:x}", window_address)
+            }
+            HyprctlEvents::MoveOutOfGroup { window_address } => {
+                write!(f, "moveoutofgroup>>{:x}", window_address)
+            }
+            HyprctlEvents::IgnoreGroupLock(state) => write!(f, "ignoregrouplock>>{}", state),
+            HyprctlEvents::LockGroups(state) => write!(f, "lockgroups>>{}", state),
+            HyprctlEvents::ConfigReloaded => write!(f, "configreloaded>>"),
+            HyprctlEvents::Pin {
+                window_address,
+                pin_state,
+            } => {
+                write!(f, "pin>>{:x},{}", window_address, pin_state)
+            }
+            HyprctlEvents::Bell { window_address } => {
+                write!(f, "bell>>{}", optional_hex(window_address))
+            }
+            // synthetic; hyprland never sends this, but it decodes back all the same
+            HyprctlEvents::Reconnected => write!(f, "reconnected>>"),
+            HyprctlEvents::Unknown { name, args } => write!(f, "{}>>{}", name, args),
+        }
+    }
+}
+
+// splits event args into exactly N comma separated fields
+// hyprland doesn't escape commas; only the last field may contain them
+// (titles, descriptions, names), so it gets everything after the N-1th comma
+fn fields<const N: usize>(event_args: &str) -> Option<[&str; N]> {
+    let mut split = event_args.splitn(N, ',');
+    let mut fields = [""; N];
+    for field in fields.iter_mut() {
+        *field = split.next()?;
+    }
+
+    Some(fields)
+}
+
+// togglegroup's address list, skipping the empty entry a group without
+// windows leaves behind
+fn handles(handle: &str) -> impl Iterator<Item = &str> {
+    handle.split(',').filter(|a| !a.is_empty())
+}
+
+// some fields are left empty instead of omitted, e.g. `activewindowv2>>` once
+// nothing has focus
+fn optional<T: FromStr>(field: &str) -> std::result::Result<Option<T>, T::Err> {
+    match field {
+        "" => Ok(None),
+        field => field.parse().map(Some),
+    }
+}
+
+fn optional_display<T: fmt::Display>(field: &Option<T>) -> String {
+    field.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+fn optional_hex(address: &Option<WindowAddress>) -> String {
+    address.map(|a| format!("{:x}", a)).unwrap_or_default()
 }
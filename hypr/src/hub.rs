@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+use log::warn;
+use tokio::{sync::broadcast, task::JoinHandle};
+
+use crate::{
+    control::HyprControl,
+    controller::Controller,
+    events::{HyprctlEventKind, HyprctlEvents},
+    Hypr,
+};
+
+pub use broadcast::error::RecvError;
+
+// reads the event socket once and fans every event out to any number of subscribers
+// each subscriber gets its own queue of `capacity` events; one that falls further
+// behind than that skips ahead and is told how many events it missed
+pub struct EventHub<C = Controller> {
+    sender: broadcast::Sender<HyprctlEvents>,
+    controller: C,
+    reader: JoinHandle<()>,
+}
+
+impl<C: HyprControl> EventHub<C> {
+    pub fn new(mut hypr: Hypr<C>, capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        let controller = hypr.controller().clone();
+
+        let reader = tokio::spawn({
+            let sender = sender.clone();
+            async move {
+                loop {
+                    match hypr.next().await {
+                        // no subscribers right now is not an error
+                        Ok(event) => _ = sender.send(event),
+                        Err(e) if e.is_bad_event() => {
+                            warn!("received hypr events but could not decode: {}", e)
+                        }
+                        // only once reconnecting (if enabled) has given up
+                        Err(e) => {
+                            warn!("hypr event socket is gone, closing hub: {}", e);
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+
+        Self {
+            sender,
+            controller,
+            reader,
+        }
+    }
+
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
+
+    // receives every event
+    pub fn subscribe(&self) -> Subscription {
+        Subscription {
+            receiver: self.sender.subscribe(),
+            kinds: None,
+        }
+    }
+
+    // receives only events of the given kinds
+    pub fn subscribe_to(&self, kinds: &[HyprctlEventKind]) -> Subscription {
+        Subscription {
+            receiver: self.sender.subscribe(),
+            kinds: Some(kinds.iter().copied().collect()),
+        }
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl<C> Drop for EventHub<C> {
+    fn drop(&mut self) {
+        // subscribers see `RecvError::Closed` once the reader is gone
+        self.reader.abort();
+    }
+}
+
+pub struct Subscription {
+    receiver: broadcast::Receiver<HyprctlEvents>,
+    kinds: Option<HashSet<HyprctlEventKind>>,
+}
+
+impl Subscription {
+    // `RecvError::Lagged(n)` means n events were dropped because this subscriber fell
+    // behind; the next call picks up from the oldest event still queued
+    pub async fn recv(&mut self) -> Result<HyprctlEvents, RecvError> {
+        loop {
+            let event = match self.receiver.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(missed)) => {
+                    warn!("hypr subscriber lagged behind, {} events dropped", missed);
+                    return Err(RecvError::Lagged(missed));
+                }
+                Err(e) => return Err(e),
+            };
+
+            match &self.kinds {
+                Some(kinds) if !kinds.contains(&HyprctlEventKind::from(&event)) => continue,
+                _ => return Ok(event),
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+// identifiers shared by events, controller replies and downstream state,
+// parsed once at the edge instead of passed around as strings
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// hyprland numbers special workspaces up from here (-99, -98, ...); named ones
+// count down from -1337, regular ones are positive and -1 means none at all
+const SPECIAL_WORKSPACE_START: i32 = -99;
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(transparent)]
+pub struct WorkspaceId(pub i32);
+
+impl WorkspaceId {
+    pub fn is_special(&self) -> bool {
+        (SPECIAL_WORKSPACE_START..-1).contains(&self.0)
+    }
+
+    // named workspaces and special ones get negative ids
+    pub fn is_regular(&self) -> bool {
+        self.0 > 0
+    }
+}
+
+impl FromStr for WorkspaceId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(WorkspaceId(s.parse()?))
+    }
+}
+
+impl fmt::Display for WorkspaceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<i32> for WorkspaceId {
+    fn from(id: i32) -> Self {
+        WorkspaceId(id)
+    }
+}
+
+// events carry bare hex, hyprctl replies and dispatchers use a `0x` prefix;
+// both parse, `Display` writes the prefixed form and `{:x}` the bare one
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WindowAddress(pub u64);
+
+impl FromStr for WindowAddress {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix("0x").unwrap_or(s);
+        Ok(WindowAddress(u64::from_str_radix(hex, 16)?))
+    }
+}
+
+impl fmt::Display for WindowAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:x}", self.0)
+    }
+}
+
+impl fmt::LowerHex for WindowAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl Serialize for WindowAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for WindowAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let address = String::deserialize(deserializer)?;
+        address.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct MonitorName(pub String);
+
+impl MonitorName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for MonitorName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for MonitorName {
+    fn from(name: &str) -> Self {
+        MonitorName(name.to_string())
+    }
+}
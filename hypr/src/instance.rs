@@ -0,0 +1,118 @@
+use std::{env, time::SystemTime};
+
+use crate::{
+    error::{HyprError, Result},
+    sock::{new_hyprctl_socket, SocketTypes},
+    Hypr,
+};
+
+// hyprland < 0.40 kept its sockets under /tmp/hypr instead of $XDG_RUNTIME_DIR/hypr
+const LEGACY_RUNTIME_DIR: &str = "/tmp";
+
+// a running hyprland, i.e. a `<runtime dir>/hypr/<signature>` whose event socket accepts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instance {
+    pub xdg_runtime_dir: String,
+    pub signature: String,
+    pub modified: SystemTime,
+}
+
+impl Instance {
+    // the instance we were started under if it is still alive, else the newest live one
+    pub async fn discover() -> Result<Self> {
+        let instances = Self::list().await;
+
+        if let Ok(signature) = env::var("HYPRLAND_INSTANCE_SIGNATURE") {
+            if let Some(instance) = instances.iter().find(|i| i.signature == signature) {
+                return Ok(instance.clone());
+            }
+        }
+
+        instances
+            .into_iter()
+            .next()
+            .ok_or_else(|| HyprError::NoLiveInstance {
+                searched: runtime_dirs().join(", "),
+            })
+    }
+
+    // picks an instance by its position in `list` or by (a prefix of) its signature,
+    // the same way `hyprctl --instance` does
+    pub async fn select(selector: &str) -> Result<Self> {
+        let instances = Self::list().await;
+
+        let found = match selector.parse::<usize>() {
+            Ok(index) if index < instances.len() => instances.into_iter().nth(index),
+            _ => instances
+                .into_iter()
+                .find(|i| i.signature.starts_with(selector)),
+        };
+
+        found.ok_or_else(|| HyprError::InstanceNotFound {
+            selector: selector.to_string(),
+        })
+    }
+
+    // every live instance across $XDG_RUNTIME_DIR and the legacy /tmp, newest first
+    pub async fn list() -> Vec<Self> {
+        let mut instances = Vec::new();
+        for xdg_runtime_dir in runtime_dirs() {
+            instances.extend(Self::list_in(&xdg_runtime_dir).await);
+        }
+
+        instances.sort_by_key(|i| std::cmp::Reverse(i.modified));
+        instances
+    }
+
+    // live instances under a single runtime dir, newest first
+    // hyprland gets a fresh signature on every start, and a crash leaves the old dir behind
+    pub async fn list_in(xdg_runtime_dir: &str) -> Vec<Self> {
+        let Ok(entries) = std::fs::read_dir(format!("{}/hypr", xdg_runtime_dir)) else {
+            return Vec::new();
+        };
+
+        let mut candidates: Vec<_> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                Some(Instance {
+                    xdg_runtime_dir: xdg_runtime_dir.to_string(),
+                    signature: entry.file_name().into_string().ok()?,
+                    modified: entry.metadata().and_then(|m| m.modified()).ok()?,
+                })
+            })
+            .collect();
+        candidates.sort_by_key(|i| std::cmp::Reverse(i.modified));
+
+        let mut instances = Vec::new();
+        for instance in candidates {
+            if instance.is_alive().await {
+                instances.push(instance);
+            }
+        }
+
+        instances
+    }
+
+    pub async fn is_alive(&self) -> bool {
+        new_hyprctl_socket(
+            &self.xdg_runtime_dir,
+            &self.signature,
+            SocketTypes::Listener,
+        )
+        .await
+        .is_ok()
+    }
+
+    pub async fn connect(&self) -> Result<Hypr> {
+        Hypr::new(&self.xdg_runtime_dir, &self.signature).await
+    }
+}
+
+fn runtime_dirs() -> Vec<String> {
+    let mut dirs: Vec<String> = env::var("XDG_RUNTIME_DIR").into_iter().collect();
+    if !dirs.iter().any(|dir| dir == LEGACY_RUNTIME_DIR) {
+        dirs.push(LEGACY_RUNTIME_DIR.to_string());
+    }
+
+    dirs
+}
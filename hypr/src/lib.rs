@@ -1,54 +1,185 @@
+mod capabilities;
+mod control;
+mod error;
 pub mod events;
+mod hub;
+mod ids;
+mod instance;
 mod sock;
 
-mod controller;
+pub mod controller;
 mod listener;
+mod stream;
+mod trace;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "testing")]
+pub mod testing;
 
-use anyhow::anyhow;
 use controller::{invoke::Method, Controller};
-use events::HyprctlEvents;
+use events::{HyprctlEventRef, HyprctlEvents};
 use listener::Listener;
-use tokio::io::{AsyncBufReadExt, BufReader, Lines};
+use log::{info, warn};
+use tokio::io::{AsyncBufReadExt, BufReader};
 
+pub use capabilities::{Capabilities, HyprlandVersion};
+pub use control::HyprControl;
 pub use controller::invoke::info::*;
+pub use error::{HyprError, Result};
+pub use hub::{EventHub, RecvError, Subscription};
+pub use ids::{MonitorName, WindowAddress, WorkspaceId};
+pub use instance::Instance;
+pub use listener::ReconnectPolicy;
+pub use stream::EventStream;
+pub use trace::{Recorder, Replay};
 
-pub struct Hypr {
-    listener: Lines<BufReader<Listener>>,
-    controller: Controller,
+// event socket plus whatever answers requests; that's the socket `Controller`
+// unless swapped for something else with `Hypr::with_control`
+pub struct Hypr<C = Controller> {
+    listener: BufReader<Listener>,
+    // reused for every event so `next_ref` can lend out of it
+    line: String,
+    controller: C,
+    xdg_runtime_dir: String,
+    // of the instance the event socket is attached to, which reconnects stick with
+    hyprland_instance_signature: String,
+    reconnect_policy: Option<ReconnectPolicy>,
+    recorder: Option<Recorder>,
+    capabilities: Capabilities,
 }
 
 impl Hypr {
-    pub async fn new(xdg_runtime_dir: &str, hyprland_instance_signature: &str) -> Self {
-        let listener = Listener::new(&xdg_runtime_dir, &hyprland_instance_signature).await;
-        let controller = Controller::new(&xdg_runtime_dir, &hyprland_instance_signature).await;
+    pub async fn new(xdg_runtime_dir: &str, hyprland_instance_signature: &str) -> Result<Self> {
+        let controller = Controller::new(xdg_runtime_dir, hyprland_instance_signature).await;
+
+        Self::with_control(xdg_runtime_dir, hyprland_instance_signature, controller).await
+    }
+}
 
-        let listener_as_bufread = BufReader::new(listener).lines();
+impl<C: HyprControl> Hypr<C> {
+    pub async fn with_control(
+        xdg_runtime_dir: &str,
+        hyprland_instance_signature: &str,
+        controller: C,
+    ) -> Result<Self> {
+        let listener = Listener::new(xdg_runtime_dir, hyprland_instance_signature).await?;
+        let capabilities = negotiate(&controller).await;
 
-        Self {
-            listener: listener_as_bufread,
+        Ok(Self {
+            listener: BufReader::new(listener),
+            line: String::new(),
             controller,
-        }
+            xdg_runtime_dir: xdg_runtime_dir.to_string(),
+            hyprland_instance_signature: hyprland_instance_signature.to_string(),
+            reconnect_policy: Some(ReconnectPolicy::default()),
+            recorder: None,
+            capabilities,
+        })
+    }
+
+    // None makes `next` fail once the event socket closes instead of reconnecting
+    pub fn with_reconnect_policy(mut self, reconnect_policy: Option<ReconnectPolicy>) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
     }
 
-    pub fn controller(&self) -> &Controller {
+    // tees every raw event line into a trace that `Replay` can play back
+    pub fn with_recorder(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    // as of the last (re)connect; a restart may well bring a different hyprland
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    pub fn controller(&self) -> &C {
         &self.controller
     }
 
-    pub async fn next(&mut self) -> anyhow::Result<HyprctlEvents> {
-        // read line
-        let next_line = self
-            .listener
-            .next_line()
-            .await?
-            .ok_or(anyhow!("empty line fed"))?;
+    pub async fn next(&mut self) -> Result<HyprctlEvents> {
+        self.next_ref().await.map(HyprctlEventRef::into_owned)
+    }
+
+    // like `next`, but borrows the event from the line buffer instead of
+    // allocating it; the event has to be dropped before asking for another
+    pub async fn next_ref(&mut self) -> Result<HyprctlEventRef<'_>> {
+        // read line; the socket only ends when hyprland goes away
+        self.line.clear();
+        let closed = match self.listener.read_line(&mut self.line).await {
+            Ok(1..) => {
+                let line = self.line.strip_suffix('\n').unwrap_or(&self.line);
+                // a trace is a debugging aid; losing it mustn't lose the event too
+                if let Some(recorder) = &mut self.recorder {
+                    if let Err(e) = recorder.record(line).await {
+                        warn!("could not write event trace, no longer recording: {}", e);
+                        self.recorder = None;
+                    }
+                }
 
-        // parse events
-        let ev = HyprctlEvents::decode_from_string(next_line)?;
+                // parse events
+                return HyprctlEventRef::decode(line);
+            }
+            Ok(0) => std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "hyprland event socket closed",
+            ),
+            // a garbled line doesn't mean the socket is gone
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData => return Err(e.into()),
+            Err(e) => e,
+        };
 
-        Ok(ev)
+        let Some(reconnect_policy) = &self.reconnect_policy else {
+            return Err(closed.into());
+        };
+
+        warn!("lost hyprland event socket ({}), reconnecting", closed);
+        let (listener, hyprland_instance_signature) = Listener::reconnect(
+            &self.xdg_runtime_dir,
+            &self.hyprland_instance_signature,
+            reconnect_policy,
+        )
+        .await?;
+
+        self.listener = BufReader::new(listener);
+        self.controller.reconnected(&hyprland_instance_signature);
+        self.hyprland_instance_signature = hyprland_instance_signature;
+        self.capabilities = negotiate(&self.controller).await;
+
+        Ok(HyprctlEventRef::Reconnected)
+    }
+
+    // hands the event socket over to a `Stream`; grab a `controller().clone()`
+    // beforehand if you still need to issue commands
+    pub fn into_stream(self) -> EventStream {
+        EventStream::new(self)
+    }
+
+    // shares this event socket between any number of subscribers
+    pub fn into_hub(self, capacity: usize) -> EventHub<C> {
+        EventHub::new(self, capacity)
     }
 
-    pub async fn invoke<'inv>(&self, method: Method<'inv>) -> anyhow::Result<String> {
+    pub async fn invoke<'inv>(&self, method: Method<'inv>) -> Result<String> {
         self.controller.invoke(method).await
     }
 }
+
+// an unanswered version query shouldn't keep us from listening
+async fn negotiate(controller: &impl HyprControl) -> Capabilities {
+    match controller.get_capabilities().await {
+        Ok(capabilities) => {
+            info!("hyprland capabilities {:?}", capabilities);
+            capabilities
+        }
+        Err(e) => {
+            warn!(
+                "could not query hyprland version, assuming a recent one: {}",
+                e
+            );
+            Capabilities::default()
+        }
+    }
+}
@@ -1,18 +1,95 @@
-use crate::sock::{new_hyprctl_socket, SocketTypes};
+use std::time::Duration;
+
+use crate::{
+    error::{HyprError, Result},
+    instance::Instance,
+    sock::{new_hyprctl_socket, SocketTypes},
+};
+use log::{info, warn};
 use tokio::{io::AsyncRead, net::UnixStream};
 pub struct Listener(pub UnixStream);
 
+// how hard to try getting the event socket back once hyprland goes away
+#[derive(Clone, Debug)]
+pub struct ReconnectPolicy {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+
+    // None retries forever
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            max_attempts: None,
+        }
+    }
+}
+
 impl Listener {
-    pub async fn new(xdg_runtime_dir: &str, hypr_instance_signature: &str) -> Self {
-        Listener(
-            new_hyprctl_socket(
-                xdg_runtime_dir,
-                hypr_instance_signature,
-                SocketTypes::Listener,
-            )
-            .await
-            .unwrap(),
+    pub async fn new(xdg_runtime_dir: &str, hypr_instance_signature: &str) -> Result<Self> {
+        let stream = new_hyprctl_socket(
+            xdg_runtime_dir,
+            hypr_instance_signature,
+            SocketTypes::Listener,
         )
+        .await?;
+
+        Ok(Listener(stream))
+    }
+
+    // backs off and reconnects, to the instance we were attached to while it is still
+    // alive and to the newest one otherwise (hyprland restarts under a new signature)
+    // returns the new listener along with the signature it is connected to
+    pub async fn reconnect(
+        xdg_runtime_dir: &str,
+        hypr_instance_signature: &str,
+        policy: &ReconnectPolicy,
+    ) -> Result<(Self, String)> {
+        let mut backoff = policy.initial_backoff;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            tokio::time::sleep(backoff).await;
+
+            let mut instances = Instance::list_in(xdg_runtime_dir).await;
+            let current = instances
+                .iter()
+                .position(|instance| instance.signature == hypr_instance_signature);
+            let instance = match current {
+                Some(current) => Some(instances.swap_remove(current)),
+                None => instances.into_iter().next(),
+            };
+
+            let err = match instance.map(|instance| instance.signature) {
+                Some(hypr_instance_signature) => {
+                    match Listener::new(xdg_runtime_dir, &hypr_instance_signature).await {
+                        Ok(listener) => {
+                            info!(
+                                "reconnected to hyprland instance {}",
+                                hypr_instance_signature
+                            );
+                            return Ok((listener, hypr_instance_signature));
+                        }
+                        Err(e) => e,
+                    }
+                }
+                None => HyprError::NoLiveInstance {
+                    searched: xdg_runtime_dir.to_string(),
+                },
+            };
+
+            if policy.max_attempts.is_some_and(|max| attempt >= max) {
+                return Err(err);
+            }
+
+            warn!("reconnect attempt {} failed: {}", attempt, err);
+            backoff = (backoff * 2).min(policy.max_backoff);
+        }
     }
 }
 
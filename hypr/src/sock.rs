@@ -1,6 +1,9 @@
-use anyhow::anyhow;
+use std::path::Path;
+
 use tokio::net::UnixStream;
 
+use crate::error::{HyprError, Result};
+
 pub enum SocketTypes {
     Listener,
     Controller,
@@ -10,7 +13,24 @@ pub async fn new_hyprctl_socket(
     xdg_runtime_dir: &str,
     hypr_instance_signature: &str,
     socket_variant: SocketTypes,
-) -> anyhow::Result<UnixStream> {
+) -> Result<UnixStream> {
+    let socket_path =
+        hyprctl_socket_path(xdg_runtime_dir, hypr_instance_signature, socket_variant)?;
+
+    UnixStream::connect(&socket_path)
+        .await
+        .map_err(|source| HyprError::ConnectFailed {
+            path: socket_path,
+            source,
+        })
+}
+
+// shared with the blocking client, which connects on its own
+pub fn hyprctl_socket_path(
+    xdg_runtime_dir: &str,
+    hypr_instance_signature: &str,
+    socket_variant: SocketTypes,
+) -> Result<String> {
     let socket_path = match socket_variant {
         SocketTypes::Listener => ".socket2.sock",
         SocketTypes::Controller => ".socket.sock",
@@ -21,9 +41,9 @@ pub async fn new_hyprctl_socket(
         xdg_runtime_dir, hypr_instance_signature, socket_path
     );
 
-    let Ok(stream) = UnixStream::connect(socket_path).await else {
-        return Err(anyhow!("failed to establish socket"));
-    };
+    if !Path::new(&socket_path).exists() {
+        return Err(HyprError::SocketNotFound { path: socket_path });
+    }
 
-    Ok(stream)
+    Ok(socket_path)
 }
@@ -0,0 +1,42 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{stream::BoxStream, Stream, StreamExt};
+
+use crate::{control::HyprControl, error::Result, events::HyprctlEvents, Hypr};
+
+// `Hypr::next` (or `Replay::next`) as a stream, for use with `StreamExt` combinators
+// a line that fails to decode is yielded as an error and the stream carries on; a
+// lost socket shows up as `HyprctlEvents::Reconnected` when reconnecting is enabled,
+// otherwise (or once reconnecting gives up) as one last error before the stream ends
+pub struct EventStream(BoxStream<'static, Result<HyprctlEvents>>);
+
+impl EventStream {
+    pub(crate) fn new(hypr: Hypr<impl HyprControl>) -> Self {
+        let events = futures::stream::unfold(Some(hypr), |hypr| async move {
+            let mut hypr = hypr?;
+            match hypr.next().await {
+                Err(e) if !e.is_bad_event() => Some((Err(e), None)),
+                next_event => Some((next_event, Some(hypr))),
+            }
+        });
+
+        Self::from_stream(events)
+    }
+
+    pub(crate) fn from_stream(
+        events: impl Stream<Item = Result<HyprctlEvents>> + Send + 'static,
+    ) -> Self {
+        EventStream(events.boxed())
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Result<HyprctlEvents>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_next_unpin(cx)
+    }
+}
@@ -0,0 +1,271 @@
+// in-process stand-in for a running hyprland, for tests that need the sockets
+// but not a compositor
+use std::{collections::HashMap, io, sync::Arc};
+
+use tempfile::TempDir;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{UnixListener, UnixStream},
+    sync::Mutex,
+    task::JoinHandle,
+};
+
+use crate::{
+    control::HyprControl, controller::invoke::Method, error::Result, events::HyprctlEvents,
+};
+
+// what hyprland answers to requests it doesn't know
+const UNKNOWN_REQUEST_REPLY: &str = "unknown request";
+
+// answered out of the box, since `Hypr::new` asks for it; override with `reply`
+const VERSION_REPLY: &str = r#"{
+    "branch": "main",
+    "commit": "mock",
+    "version": "0.45.0",
+    "dirty": false,
+    "commit_message": "",
+    "commit_date": "",
+    "tag": "v0.45.0",
+    "commits": "0",
+    "flags": []
+}"#;
+
+pub struct MockHyprland {
+    // shared with siblings
+    runtime_dir: Arc<TempDir>,
+    instance_signature: String,
+    // None never answers, like a hung hyprland
+    replies: Arc<Mutex<HashMap<String, Option<String>>>>,
+    requests: Arc<Mutex<Vec<String>>>,
+    listeners: Arc<Mutex<EventListeners>>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+#[derive(Default)]
+struct EventListeners {
+    streams: Vec<UnixStream>,
+
+    // events pushed while nobody listens go to the next listener that connects
+    backlog: Vec<Vec<u8>>,
+}
+
+impl MockHyprland {
+    // binds `.socket.sock` and `.socket2.sock` under a fresh temporary runtime dir
+    pub async fn start() -> io::Result<Self> {
+        Self::start_in(Arc::new(tempfile::tempdir()?), "mock_hyprland_instance").await
+    }
+
+    // another hyprland running next to this one, under the same runtime dir
+    pub async fn start_sibling(&self, instance_signature: &str) -> io::Result<Self> {
+        Self::start_in(self.runtime_dir.clone(), instance_signature).await
+    }
+
+    async fn start_in(runtime_dir: Arc<TempDir>, instance_signature: &str) -> io::Result<Self> {
+        let instance_signature = instance_signature.to_string();
+
+        let instance_dir = runtime_dir.path().join("hypr").join(&instance_signature);
+        std::fs::create_dir_all(&instance_dir)?;
+
+        let controller_socket = UnixListener::bind(instance_dir.join(".socket.sock"))?;
+        let listener_socket = UnixListener::bind(instance_dir.join(".socket2.sock"))?;
+
+        let replies = Arc::new(Mutex::new(HashMap::from([(
+            "j/version".to_string(),
+            Some(VERSION_REPLY.to_string()),
+        )])));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let listeners = Arc::new(Mutex::new(EventListeners::default()));
+
+        let tasks = vec![
+            tokio::spawn(serve_controller(
+                controller_socket,
+                replies.clone(),
+                requests.clone(),
+            )),
+            tokio::spawn(serve_listeners(listener_socket, listeners.clone())),
+        ];
+
+        Ok(Self {
+            runtime_dir,
+            instance_signature,
+            replies,
+            requests,
+            listeners,
+            tasks,
+        })
+    }
+
+    // pass these to `Hypr::new` / `Controller::new`
+    pub fn xdg_runtime_dir(&self) -> &str {
+        self.runtime_dir
+            .path()
+            .to_str()
+            .expect("temp dir path is not utf-8")
+    }
+
+    pub fn instance_signature(&self) -> &str {
+        &self.instance_signature
+    }
+
+    // answers `request` (exactly as written to the socket, e.g. "j/workspaces")
+    // with `reply` from now on
+    pub async fn reply(&self, request: &str, reply: &str) {
+        self.replies
+            .lock()
+            .await
+            .insert(request.to_string(), Some(reply.to_string()));
+    }
+
+    // accepts `request` from now on but never answers it
+    pub async fn stall(&self, request: &str) {
+        self.replies.lock().await.insert(request.to_string(), None);
+    }
+
+    // every request received on the controller socket so far, oldest first
+    pub async fn requests(&self) -> Vec<String> {
+        self.requests.lock().await.clone()
+    }
+
+    // sends `line` (without the trailing newline) to every connected event listener
+    pub async fn push_event(&self, line: &str) {
+        self.push_raw_event(line.as_bytes()).await;
+    }
+
+    // like `push_event`, for lines that aren't valid utf-8
+    pub async fn push_raw_event(&self, line: &[u8]) {
+        let mut listeners = self.listeners.lock().await;
+        let line = [line, b"\n"].concat();
+
+        if listeners.streams.is_empty() {
+            listeners.backlog.push(line);
+            return;
+        }
+
+        let mut connected = Vec::new();
+        for mut stream in listeners.streams.drain(..) {
+            if stream.write_all(&line).await.is_ok() {
+                connected.push(stream);
+            }
+        }
+        listeners.streams = connected;
+    }
+
+    pub async fn push(&self, event: &HyprctlEvents) {
+        self.push_event(&event.encode_to_string()).await;
+    }
+
+    // closes every event socket, as a hyprland restart would
+    pub async fn disconnect_listeners(&self) {
+        self.listeners.lock().await.streams.clear();
+    }
+}
+
+impl Drop for MockHyprland {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+async fn serve_controller(
+    socket: UnixListener,
+    replies: Arc<Mutex<HashMap<String, Option<String>>>>,
+    requests: Arc<Mutex<Vec<String>>>,
+) {
+    // kept open, unanswered
+    let mut stalled = Vec::new();
+
+    while let Ok((mut stream, _)) = socket.accept().await {
+        // requests are tiny and written in one go, just like hyprland assumes
+        let mut request = vec![0; 8192];
+        let Ok(read) = stream.read(&mut request).await else {
+            continue;
+        };
+        let request = String::from_utf8_lossy(&request[..read]).to_string();
+
+        let reply = replies
+            .lock()
+            .await
+            .get(&request)
+            .cloned()
+            .unwrap_or_else(|| Some(UNKNOWN_REQUEST_REPLY.to_string()));
+        requests.lock().await.push(request);
+
+        let Some(reply) = reply else {
+            stalled.push(stream);
+            continue;
+        };
+
+        // one request per connection; hyprland closes the socket after replying
+        let _ = stream.write_all(reply.as_bytes()).await;
+        let _ = stream.shutdown().await;
+    }
+}
+
+async fn serve_listeners(socket: UnixListener, listeners: Arc<Mutex<EventListeners>>) {
+    while let Ok((mut stream, _)) = socket.accept().await {
+        let mut listeners = listeners.lock().await;
+
+        for line in listeners.backlog.drain(..).collect::<Vec<_>>() {
+            let _ = stream.write_all(&line).await;
+        }
+        listeners.streams.push(stream);
+    }
+}
+
+// in-memory `HyprControl` for code that only needs the controller; scripted the
+// same way as `MockHyprland`, minus the sockets
+#[derive(Clone)]
+pub struct FakeControl {
+    replies: Arc<std::sync::Mutex<HashMap<String, String>>>,
+    requests: Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl Default for FakeControl {
+    fn default() -> Self {
+        Self {
+            replies: Arc::new(std::sync::Mutex::new(HashMap::from([(
+                "j/version".to_string(),
+                VERSION_REPLY.to_string(),
+            )]))),
+            requests: Arc::default(),
+        }
+    }
+}
+
+impl FakeControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // answers `request` (e.g. "j/workspaces") with `reply` from now on
+    pub fn reply(&self, request: &str, reply: &str) {
+        self.replies
+            .lock()
+            .unwrap()
+            .insert(request.to_string(), reply.to_string());
+    }
+
+    // every request invoked so far, oldest first
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl HyprControl for FakeControl {
+    async fn invoke(&self, method: Method<'_>) -> Result<String> {
+        let request = method.to_string();
+
+        let reply = self
+            .replies
+            .lock()
+            .unwrap()
+            .get(&request)
+            .cloned()
+            .unwrap_or_else(|| UNKNOWN_REQUEST_REPLY.to_string());
+        self.requests.lock().unwrap().push(request);
+
+        Ok(reply)
+    }
+}
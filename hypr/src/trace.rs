@@ -0,0 +1,102 @@
+// record raw socket2 lines with their timing, and play them back later
+// trace format: one `<microseconds since recording started>\t<raw line>` per line
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use tokio::{
+    fs::File,
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter, Lines},
+    time,
+};
+
+use crate::{
+    error::{HyprError, Result},
+    events::HyprctlEvents,
+    stream::EventStream,
+};
+
+pub struct Recorder {
+    trace: BufWriter<File>,
+    started: Instant,
+}
+
+impl Recorder {
+    pub async fn create(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self {
+            trace: BufWriter::new(File::create(path).await?),
+            started: Instant::now(),
+        })
+    }
+
+    pub async fn record(&mut self, line: &str) -> Result<()> {
+        let elapsed = self.started.elapsed().as_micros();
+        self.trace
+            .write_all(format!("{}\t{}\n", elapsed, line).as_bytes())
+            .await?;
+
+        // flush per line so a trace survives whatever crash it is meant to explain
+        self.trace.flush().await?;
+
+        Ok(())
+    }
+}
+
+pub struct Replay {
+    trace: Lines<BufReader<File>>,
+    speed: f64,
+    started: Option<time::Instant>,
+}
+
+impl Replay {
+    // speed scales the recorded delays: 1.0 is real time, 2.0 twice as fast,
+    // 0.0 (or anything not positive) replays without waiting at all
+    pub async fn open(path: impl AsRef<Path>, speed: f64) -> Result<Self> {
+        Ok(Self {
+            trace: BufReader::new(File::open(path).await?).lines(),
+            speed,
+            started: None,
+        })
+    }
+
+    // next raw line, once its (scaled) recorded time has come
+    pub async fn next_line(&mut self) -> Result<Option<String>> {
+        let Some(entry) = self.trace.next_line().await? else {
+            return Ok(None);
+        };
+
+        let malformed = || HyprError::MalformedEvent {
+            line: entry.clone(),
+        };
+        let (elapsed, line) = entry.split_once('\t').ok_or_else(malformed)?;
+        let elapsed: u64 = elapsed.parse().map_err(|_| malformed())?;
+
+        let started = *self.started.get_or_insert_with(time::Instant::now);
+        if self.speed > 0.0 {
+            // a small enough speed pushes the due time past what `Duration` can hold;
+            // such a line is due never, rather than a panic
+            let due = Duration::try_from_secs_f64(elapsed as f64 / 1e6 / self.speed)
+                .unwrap_or(Duration::MAX);
+            time::sleep(due.saturating_sub(started.elapsed())).await;
+        }
+
+        Ok(Some(line.to_string()))
+    }
+
+    pub async fn next(&mut self) -> Option<Result<HyprctlEvents>> {
+        match self.next_line().await {
+            Ok(Some(line)) => Some(HyprctlEvents::decode_from_string(line)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    // unlike a live `Hypr` stream, this one ends with the trace
+    pub fn into_stream(self) -> EventStream {
+        EventStream::from_stream(futures::stream::unfold(self, |mut replay| async move {
+            let next_event = replay.next().await?;
+            Some((next_event, replay))
+        }))
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use hypr::{
+    blocking::{Controller, Listener},
+    controller::dispatch::{Dispatcher, WorkspaceSelector},
+    events::HyprctlEvents,
+    testing::MockHyprland,
+    HyprError, WorkspaceId,
+};
+
+// the mock needs a runtime; the blocking calls get a thread of their own
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    tokio::task::spawn_blocking(f).await.unwrap()
+}
+
+#[tokio::test]
+async fn controller_shares_requests_and_models() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply("j/activewindow", r#"{"class": "kitty", "title": "~/src"}"#)
+        .await;
+    mock.reply("dispatch workspace 3", "ok").await;
+    let controller = Controller::new(mock.xdg_runtime_dir(), mock.instance_signature());
+
+    let (active_window, dispatched, capabilities) = blocking(move || {
+        (
+            controller.get_active_window().unwrap(),
+            controller.dispatch(Dispatcher::Workspace(WorkspaceSelector::Id(WorkspaceId(3)))),
+            controller.get_capabilities().unwrap(),
+        )
+    })
+    .await;
+
+    assert_eq!(active_window.class, "kitty");
+    assert!(dispatched.is_ok());
+    assert!(capabilities.workspace_v2_event);
+    assert_eq!(
+        mock.requests().await,
+        vec!["j/activewindow", "dispatch workspace 3", "j/version"]
+    );
+}
+
+#[tokio::test]
+async fn controller_times_out() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.stall("j/clients").await;
+    let controller = Controller::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .with_response_timeout(Duration::from_millis(50));
+
+    let clients = blocking(move || controller.get_clients()).await;
+
+    assert!(matches!(clients, Err(HyprError::Timeout)));
+}
+
+#[tokio::test]
+async fn listener_iterates_events() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.push_event("workspacev2>>2,2").await;
+    mock.push_event("activewindowv2>>").await;
+    let mut listener = Listener::new(mock.xdg_runtime_dir(), mock.instance_signature()).unwrap();
+
+    let events = blocking(move || {
+        [
+            listener.next().unwrap().unwrap(),
+            listener.next().unwrap().unwrap(),
+        ]
+    })
+    .await;
+
+    assert_eq!(
+        events,
+        [
+            HyprctlEvents::WorkspaceV2 {
+                workspace_id: WorkspaceId(2),
+                workspace_name: "2".to_string(),
+            },
+            HyprctlEvents::ActiveWindowV2 {
+                window_address: None
+            },
+        ]
+    );
+}
@@ -0,0 +1,172 @@
+use hypr::{
+    controller::dispatch::{
+        Direction, Dispatcher, FullscreenMode, Resize, WindowSelector, WorkspaceSelector,
+    },
+    WindowAddress, WorkspaceId,
+};
+
+// what follows `dispatch ` on the hyprctl socket
+#[test]
+fn dispatchers_render_hyprctl_arguments() {
+    let cases = [
+        (
+            Dispatcher::Exec("kitty --single-instance"),
+            "exec kitty --single-instance",
+        ),
+        (Dispatcher::KillActive, "killactive"),
+        (
+            Dispatcher::CloseWindow(WindowSelector::Class("^firefox$")),
+            "closewindow class:^firefox$",
+        ),
+        (
+            Dispatcher::Workspace(WorkspaceSelector::Id(WorkspaceId(3))),
+            "workspace 3",
+        ),
+        (
+            Dispatcher::MoveToWorkspace(WorkspaceSelector::Id(WorkspaceId(3)), None),
+            "movetoworkspace 3",
+        ),
+        (
+            Dispatcher::MoveToWorkspace(
+                WorkspaceSelector::Name("mail"),
+                Some(WindowSelector::Pid(4242)),
+            ),
+            "movetoworkspace name:mail,pid:4242",
+        ),
+        (
+            Dispatcher::MoveToWorkspaceSilent(WorkspaceSelector::Relative(1), None),
+            "movetoworkspacesilent +1",
+        ),
+        (
+            Dispatcher::MoveToWorkspaceSilent(
+                WorkspaceSelector::Special(Some("scratch")),
+                Some(WindowSelector::ActiveWindow),
+            ),
+            "movetoworkspacesilent special:scratch,activewindow",
+        ),
+        (Dispatcher::ToggleFloating(None), "togglefloating"),
+        (
+            Dispatcher::ToggleFloating(Some(WindowSelector::Title("Picture-in-Picture"))),
+            "togglefloating title:Picture-in-Picture",
+        ),
+        (
+            Dispatcher::Fullscreen(FullscreenMode::Fullscreen),
+            "fullscreen 0",
+        ),
+        (
+            Dispatcher::Fullscreen(FullscreenMode::Maximize),
+            "fullscreen 1",
+        ),
+        (Dispatcher::Pseudo(None), "pseudo"),
+        (
+            Dispatcher::Pseudo(Some(WindowSelector::Floating)),
+            "pseudo floating",
+        ),
+        (Dispatcher::Pin(None), "pin"),
+        (Dispatcher::Pin(Some(WindowSelector::Tiled)), "pin tiled"),
+        (Dispatcher::MoveFocus(Direction::Left), "movefocus l"),
+        (Dispatcher::MoveWindow(Direction::Right), "movewindow r"),
+        (Dispatcher::SwapWindow(Direction::Up), "swapwindow u"),
+        (Dispatcher::CenterWindow, "centerwindow"),
+        (
+            Dispatcher::ResizeActive(Resize::Relative(-10, 20)),
+            "resizeactive -10 20",
+        ),
+        (
+            Dispatcher::MoveActive(Resize::Exact(100, 200)),
+            "moveactive exact 100 200",
+        ),
+        (Dispatcher::CycleNext, "cyclenext"),
+        (Dispatcher::CyclePrev, "cyclenext prev"),
+        (
+            Dispatcher::FocusWindow(WindowSelector::Address(WindowAddress(0x55d0c0ee9a60))),
+            "focuswindow address:0x55d0c0ee9a60",
+        ),
+        (Dispatcher::FocusMonitor("DP-1"), "focusmonitor DP-1"),
+        (
+            Dispatcher::ToggleSpecialWorkspace(None),
+            "togglespecialworkspace",
+        ),
+        (
+            Dispatcher::ToggleSpecialWorkspace(Some("scratch")),
+            "togglespecialworkspace scratch",
+        ),
+        (Dispatcher::ToggleGroup, "togglegroup"),
+        (Dispatcher::Submap("resize"), "submap resize"),
+        (Dispatcher::Exit, "exit"),
+        (
+            Dispatcher::Other("layoutmsg", "orientationtop"),
+            "layoutmsg orientationtop",
+        ),
+        (Dispatcher::Other("dpms", "off DP-1"), "dpms off DP-1"),
+    ];
+
+    for (dispatcher, expected) in cases {
+        assert_eq!(dispatcher.to_string(), expected);
+    }
+}
+
+#[test]
+fn window_selectors_render_hyprctl_arguments() {
+    let cases = [
+        (WindowSelector::Class("kitty"), "class:kitty"),
+        (WindowSelector::InitialClass("kitty"), "initialclass:kitty"),
+        (WindowSelector::Title("~/src"), "title:~/src"),
+        (WindowSelector::InitialTitle("zsh"), "initialtitle:zsh"),
+        (WindowSelector::Tag("work"), "tag:work"),
+        (WindowSelector::Pid(4242), "pid:4242"),
+        (
+            WindowSelector::Address(WindowAddress(0x55d0c0ee9a60)),
+            "address:0x55d0c0ee9a60",
+        ),
+        (WindowSelector::ActiveWindow, "activewindow"),
+        (WindowSelector::Floating, "floating"),
+        (WindowSelector::Tiled, "tiled"),
+    ];
+
+    for (selector, expected) in cases {
+        assert_eq!(selector.to_string(), expected);
+    }
+}
+
+#[test]
+fn workspace_selectors_render_hyprctl_arguments() {
+    let cases = [
+        (WorkspaceSelector::Id(WorkspaceId(3)), "3"),
+        (WorkspaceSelector::Relative(1), "+1"),
+        (WorkspaceSelector::Relative(-2), "-2"),
+        (WorkspaceSelector::MonitorRelative(1), "m+1"),
+        (WorkspaceSelector::MonitorRelative(-1), "m-1"),
+        (WorkspaceSelector::OpenRelative(2), "r+2"),
+        (WorkspaceSelector::Name("mail"), "name:mail"),
+        (WorkspaceSelector::Previous, "previous"),
+        (
+            WorkspaceSelector::PreviousPerMonitor,
+            "previous_per_monitor",
+        ),
+        (WorkspaceSelector::Empty, "empty"),
+        (WorkspaceSelector::Special(None), "special"),
+        (
+            WorkspaceSelector::Special(Some("scratch")),
+            "special:scratch",
+        ),
+    ];
+
+    for (selector, expected) in cases {
+        assert_eq!(selector.to_string(), expected);
+    }
+}
+
+#[test]
+fn arguments_render_hyprctl_arguments() {
+    assert_eq!(Direction::Left.to_string(), "l");
+    assert_eq!(Direction::Right.to_string(), "r");
+    assert_eq!(Direction::Up.to_string(), "u");
+    assert_eq!(Direction::Down.to_string(), "d");
+
+    assert_eq!(FullscreenMode::Fullscreen.to_string(), "0");
+    assert_eq!(FullscreenMode::Maximize.to_string(), "1");
+
+    assert_eq!(Resize::Relative(10, -10).to_string(), "10 -10");
+    assert_eq!(Resize::Exact(1920, 1080).to_string(), "exact 1920 1080");
+}
@@ -0,0 +1,97 @@
+use hypr::{events::HyprctlEvents, MonitorName};
+use proptest::prelude::*;
+
+// the last field of an event may carry anything but a newline
+const FREE_TEXT: &str = "[^\n]*";
+// leading fields never contain commas, but may contain `>>`
+const FIELD: &str = "[^,\n]*";
+const ADDRESS: &str = "[0-9a-f]{1,16}";
+
+#[test]
+fn title_with_commas_is_kept_whole() {
+    let decoded = HyprctlEvents::decode_from_string(
+        "activewindow>>firefox,Inbox, 3 unread - Mail".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        decoded,
+        HyprctlEvents::ActiveWindow {
+            window_class: "firefox".to_string(),
+            window_title: "Inbox, 3 unread - Mail".to_string(),
+        }
+    );
+}
+
+proptest! {
+    #[test]
+    fn activewindow_round_trips(class in FIELD, title in FREE_TEXT) {
+        let line = format!("activewindow>>{},{}", class, title);
+        let decoded = HyprctlEvents::decode_from_string(line).unwrap();
+
+        prop_assert_eq!(decoded, HyprctlEvents::ActiveWindow {
+            window_class: class,
+            window_title: title,
+        });
+    }
+
+    #[test]
+    fn openwindow_round_trips(
+        address in ADDRESS,
+        workspace in FIELD,
+        class in FIELD,
+        title in FREE_TEXT,
+    ) {
+        let line = format!("openwindow>>{},{},{},{}", address, workspace, class, title);
+        let decoded = HyprctlEvents::decode_from_string(line).unwrap();
+
+        prop_assert_eq!(decoded, HyprctlEvents::OpenWindow {
+            window_address: address.parse().unwrap(),
+            workspace_name: workspace,
+            window_class: class,
+            window_title: title,
+        });
+    }
+
+    #[test]
+    fn windowtitlev2_round_trips(address in ADDRESS, title in FREE_TEXT) {
+        let line = format!("windowtitlev2>>{},{}", address, title);
+        let decoded = HyprctlEvents::decode_from_string(line).unwrap();
+
+        prop_assert_eq!(decoded, HyprctlEvents::WindowTitleV2 {
+            window_address: address.parse().unwrap(),
+            window_title: title,
+        });
+    }
+
+    #[test]
+    fn renameworkspace_round_trips(id in "-?[0-9]{1,4}", name in FREE_TEXT) {
+        let line = format!("renameworkspace>>{},{}", id, name);
+        let decoded = HyprctlEvents::decode_from_string(line).unwrap();
+
+        prop_assert_eq!(decoded, HyprctlEvents::RenameWorkspace {
+            workspace_id: id.parse().unwrap(),
+            new_name: name,
+        });
+    }
+
+    #[test]
+    fn monitoraddedv2_round_trips(id in "[0-9]{1,2}", name in FIELD, description in FREE_TEXT) {
+        let line = format!("monitoraddedv2>>{},{},{}", id, name, description);
+        let decoded = HyprctlEvents::decode_from_string(line).unwrap();
+
+        prop_assert_eq!(decoded, HyprctlEvents::MonitorAddedV2 {
+            monitor_id: id,
+            monitor_name: MonitorName(name),
+            monitor_description: description,
+        });
+    }
+
+    #[test]
+    fn single_field_events_keep_commas(name in FREE_TEXT) {
+        let line = format!("workspace>>{}", name);
+        let decoded = HyprctlEvents::decode_from_string(line).unwrap();
+
+        prop_assert_eq!(decoded, HyprctlEvents::Workspace { workspace_name: name });
+    }
+}
@@ -0,0 +1,190 @@
+use hypr::{
+    events::{HyprctlEventRef, HyprctlEvents},
+    MonitorName, WindowAddress, WorkspaceId,
+};
+use proptest::prelude::*;
+
+// only the last field of an event may contain commas
+fn field() -> impl Strategy<Value = String> {
+    "[^,\n]*"
+}
+
+fn last() -> impl Strategy<Value = String> {
+    "[^\n]*"
+}
+
+fn workspace_id() -> impl Strategy<Value = WorkspaceId> {
+    any::<i32>().prop_map(WorkspaceId)
+}
+
+fn address() -> impl Strategy<Value = WindowAddress> {
+    any::<u64>().prop_map(WindowAddress)
+}
+
+fn monitor() -> impl Strategy<Value = MonitorName> {
+    field().prop_map(MonitorName)
+}
+
+fn events() -> impl Strategy<Value = HyprctlEvents> {
+    use HyprctlEvents::*;
+
+    prop_oneof![
+        last().prop_map(|workspace_name| Workspace { workspace_name }),
+        (workspace_id(), last()).prop_map(|(workspace_id, workspace_name)| WorkspaceV2 {
+            workspace_id,
+            workspace_name
+        }),
+        (monitor(), last()).prop_map(|(mon_name, workspace_name)| FocusedMon {
+            mon_name,
+            workspace_name
+        }),
+        (monitor(), workspace_id()).prop_map(|(mon_name, workspace_id)| FocusedMonV2 {
+            mon_name,
+            workspace_id
+        }),
+        (field(), last()).prop_map(|(window_class, window_title)| ActiveWindow {
+            window_class,
+            window_title
+        }),
+        prop::option::of(address()).prop_map(|window_address| ActiveWindowV2 { window_address }),
+        any::<bool>().prop_map(FullScreen),
+        monitor().prop_map(|monitor_name| MonitorRemoved { monitor_name }),
+        monitor().prop_map(|monitor_name| MonitorAdded { monitor_name }),
+        (field(), monitor(), last()).prop_map(|(monitor_id, monitor_name, monitor_description)| {
+            MonitorAddedV2 {
+                monitor_id,
+                monitor_name,
+                monitor_description,
+            }
+        }),
+        last().prop_map(|workspace_name| CreateWorkspace { workspace_name }),
+        (workspace_id(), last()).prop_map(|(workspace_id, workspace_name)| CreateWorkspaceV2 {
+            workspace_id,
+            workspace_name
+        }),
+        last().prop_map(|workspace_name| DestroyWorkspace { workspace_name }),
+        (workspace_id(), last()).prop_map(|(workspace_id, workspace_name)| DestroyWorkspaceV2 {
+            workspace_id,
+            workspace_name
+        }),
+        (field(), monitor()).prop_map(|(workspace_name, mon_name)| MoveWorkspace {
+            workspace_name,
+            mon_name
+        }),
+        (workspace_id(), field(), monitor()).prop_map(
+            |(workspace_id, workspace_name, mon_name)| MoveWorkspaceV2 {
+                workspace_id,
+                workspace_name,
+                mon_name
+            }
+        ),
+        (workspace_id(), last()).prop_map(|(workspace_id, new_name)| RenameWorkspace {
+            workspace_id,
+            new_name
+        }),
+        (field(), monitor()).prop_map(|(workspace_name, mon_name)| ActiveSpecial {
+            workspace_name,
+            mon_name
+        }),
+        (prop::option::of(workspace_id()), field(), monitor()).prop_map(
+            |(workspace_id, workspace_name, mon_name)| ActiveSpecialV2 {
+                workspace_id,
+                workspace_name,
+                mon_name
+            }
+        ),
+        (field(), last()).prop_map(|(keyboard_name, layout_name)| ActiveLayout {
+            keyboard_name,
+            layout_name
+        }),
+        (address(), field(), field(), last()).prop_map(
+            |(window_address, workspace_name, window_class, window_title)| OpenWindow {
+                window_address,
+                workspace_name,
+                window_class,
+                window_title
+            }
+        ),
+        address().prop_map(|window_address| CloseWindow { window_address }),
+        (address(), last()).prop_map(|(window_address, workspace_name)| MoveWindow {
+            window_address,
+            workspace_name
+        }),
+        (address(), workspace_id(), last()).prop_map(
+            |(window_address, workspace_id, workspace_name)| MoveWindowV2 {
+                window_address,
+                workspace_id,
+                workspace_name
+            }
+        ),
+        last().prop_map(|namespace| OpenLayer { namespace }),
+        last().prop_map(|namespace| CloseLayer { namespace }),
+        last().prop_map(|submap_name| Submap { submap_name }),
+        (address(), last()).prop_map(|(window_address, floating)| ChangeFloatingMode {
+            window_address,
+            floating
+        }),
+        address().prop_map(|window_address| Urgent { window_address }),
+        (address(), last()).prop_map(|(window_address, minimized)| Minimize {
+            window_address,
+            minimized
+        }),
+        (any::<u8>(), any::<u8>()).prop_map(|(state, owner)| Screencast { state, owner }),
+        address().prop_map(|window_address| WindowTitle { window_address }),
+        (address(), last()).prop_map(|(window_address, window_title)| WindowTitleV2 {
+            window_address,
+            window_title
+        }),
+        (any::<u8>(), prop::collection::vec(address(), 0..4))
+            .prop_map(|(state, handle)| ToggleGroup { state, handle }),
+        address().prop_map(|window_address| MoveIntoGroup { window_address }),
+        address().prop_map(|window_address| MoveOutOfGroup { window_address }),
+        any::<u8>().prop_map(IgnoreGroupLock),
+        any::<u8>().prop_map(LockGroups),
+        Just(ConfigReloaded),
+        (address(), last()).prop_map(|(window_address, pin_state)| Pin {
+            window_address,
+            pin_state
+        }),
+        prop::option::of(address()).prop_map(|window_address| Bell { window_address }),
+        Just(Reconnected),
+        // anything that isn't a known event name
+        ("x[a-z0-9]{0,12}", last()).prop_map(|(name, args)| Unknown { name, args }),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn decode_inverts_encode(event in events()) {
+        let decoded = HyprctlEvents::decode_from_string(event.encode_to_string()).unwrap();
+        prop_assert_eq!(decoded, event);
+    }
+
+    #[test]
+    fn borrowed_decode_agrees_with_owned(event in events()) {
+        let line = event.encode_to_string();
+        let borrowed = HyprctlEventRef::decode(&line).unwrap();
+        prop_assert_eq!(borrowed.into_owned(), event);
+    }
+}
+
+#[test]
+fn encodes_exact_wire_line() {
+    let event = HyprctlEvents::OpenWindow {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        workspace_name: "2".to_string(),
+        window_class: "firefox".to_string(),
+        window_title: "Inbox, 3 unread - Mail".to_string(),
+    };
+
+    assert_eq!(
+        event.encode_to_string(),
+        "openwindow>>55d0c0ee9a60,2,firefox,Inbox, 3 unread - Mail"
+    );
+    assert_eq!(HyprctlEvents::FullScreen(true).to_string(), "fullscreen>>1");
+    assert_eq!(
+        HyprctlEvents::ConfigReloaded.to_string(),
+        "configreloaded>>"
+    );
+    assert_eq!(HyprctlEvents::Reconnected.to_string(), "reconnected>>");
+}
@@ -0,0 +1,386 @@
+use hypr::{
+    events::{HyprctlEventRef, HyprctlEvents},
+    HyprError, MonitorName, WindowAddress, WorkspaceId,
+};
+
+fn s(v: &str) -> String {
+    v.to_string()
+}
+
+macro_rules! fixture {
+    ($name:ident, $line:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let decoded = HyprctlEvents::decode_from_string($line.to_string()).unwrap();
+            assert_eq!(decoded, $expected);
+        }
+    };
+}
+
+fixture!(
+    workspace,
+    "workspace>>3",
+    HyprctlEvents::Workspace {
+        workspace_name: s("3")
+    }
+);
+fixture!(
+    workspacev2,
+    "workspacev2>>3,3",
+    HyprctlEvents::WorkspaceV2 {
+        workspace_id: WorkspaceId(3),
+        workspace_name: s("3")
+    }
+);
+fixture!(
+    focusedmon,
+    "focusedmon>>DP-1,2",
+    HyprctlEvents::FocusedMon {
+        mon_name: MonitorName::from("DP-1"),
+        workspace_name: s("2")
+    }
+);
+fixture!(
+    focusedmonv2,
+    "focusedmonv2>>DP-1,2",
+    HyprctlEvents::FocusedMonV2 {
+        mon_name: MonitorName::from("DP-1"),
+        workspace_id: WorkspaceId(2)
+    }
+);
+fixture!(
+    activewindow,
+    "activewindow>>kitty,~/src",
+    HyprctlEvents::ActiveWindow {
+        window_class: s("kitty"),
+        window_title: s("~/src")
+    }
+);
+fixture!(
+    activewindowv2,
+    "activewindowv2>>55d0c0ee9a60",
+    HyprctlEvents::ActiveWindowV2 {
+        window_address: Some(WindowAddress(0x55d0c0ee9a60))
+    }
+);
+fixture!(
+    activewindowv2_unfocused,
+    "activewindowv2>>",
+    HyprctlEvents::ActiveWindowV2 {
+        window_address: None
+    }
+);
+fixture!(fullscreen, "fullscreen>>1", HyprctlEvents::FullScreen(true));
+fixture!(
+    monitorremoved,
+    "monitorremoved>>HDMI-A-1",
+    HyprctlEvents::MonitorRemoved {
+        monitor_name: MonitorName::from("HDMI-A-1")
+    }
+);
+fixture!(
+    monitoradded,
+    "monitoradded>>HDMI-A-1",
+    HyprctlEvents::MonitorAdded {
+        monitor_name: MonitorName::from("HDMI-A-1")
+    }
+);
+fixture!(
+    monitoraddedv2,
+    "monitoraddedv2>>1,HDMI-A-1,Dell Inc. DELL U2720Q",
+    HyprctlEvents::MonitorAddedV2 {
+        monitor_id: s("1"),
+        monitor_name: MonitorName::from("HDMI-A-1"),
+        monitor_description: s("Dell Inc. DELL U2720Q")
+    }
+);
+fixture!(
+    createworkspace,
+    "createworkspace>>4",
+    HyprctlEvents::CreateWorkspace {
+        workspace_name: s("4")
+    }
+);
+fixture!(
+    createworkspacev2,
+    "createworkspacev2>>4,4",
+    HyprctlEvents::CreateWorkspaceV2 {
+        workspace_id: WorkspaceId(4),
+        workspace_name: s("4")
+    }
+);
+fixture!(
+    destroyworkspace,
+    "destroyworkspace>>4",
+    HyprctlEvents::DestroyWorkspace {
+        workspace_name: s("4")
+    }
+);
+fixture!(
+    destroyworkspacev2,
+    "destroyworkspacev2>>4,4",
+    HyprctlEvents::DestroyWorkspaceV2 {
+        workspace_id: WorkspaceId(4),
+        workspace_name: s("4")
+    }
+);
+fixture!(
+    moveworkspace,
+    "moveworkspace>>4,DP-1",
+    HyprctlEvents::MoveWorkspace {
+        workspace_name: s("4"),
+        mon_name: MonitorName::from("DP-1")
+    }
+);
+fixture!(
+    moveworkspacev2,
+    "moveworkspacev2>>4,4,DP-1",
+    HyprctlEvents::MoveWorkspaceV2 {
+        workspace_id: WorkspaceId(4),
+        workspace_name: s("4"),
+        mon_name: MonitorName::from("DP-1")
+    }
+);
+fixture!(
+    renameworkspace,
+    "renameworkspace>>4,mail",
+    HyprctlEvents::RenameWorkspace {
+        workspace_id: WorkspaceId(4),
+        new_name: s("mail")
+    }
+);
+fixture!(
+    activespecial,
+    "activespecial>>special:scratch,DP-1",
+    HyprctlEvents::ActiveSpecial {
+        workspace_name: s("special:scratch"),
+        mon_name: MonitorName::from("DP-1")
+    }
+);
+fixture!(
+    activespecialv2,
+    "activespecialv2>>-98,special:scratch,DP-1",
+    HyprctlEvents::ActiveSpecialV2 {
+        workspace_id: Some(WorkspaceId(-98)),
+        workspace_name: s("special:scratch"),
+        mon_name: MonitorName::from("DP-1")
+    }
+);
+fixture!(
+    activespecialv2_closed,
+    "activespecialv2>>,,DP-1",
+    HyprctlEvents::ActiveSpecialV2 {
+        workspace_id: None,
+        workspace_name: s(""),
+        mon_name: MonitorName::from("DP-1")
+    }
+);
+fixture!(
+    activelayout,
+    "activelayout>>at-translated-set-2-keyboard,English (US)",
+    HyprctlEvents::ActiveLayout {
+        keyboard_name: s("at-translated-set-2-keyboard"),
+        layout_name: s("English (US)")
+    }
+);
+fixture!(
+    openwindow,
+    "openwindow>>55d0c0ee9a60,2,firefox,Mozilla Firefox",
+    HyprctlEvents::OpenWindow {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        workspace_name: s("2"),
+        window_class: s("firefox"),
+        window_title: s("Mozilla Firefox")
+    }
+);
+fixture!(
+    closewindow,
+    "closewindow>>55d0c0ee9a60",
+    HyprctlEvents::CloseWindow {
+        window_address: WindowAddress(0x55d0c0ee9a60)
+    }
+);
+fixture!(
+    movewindow,
+    "movewindow>>55d0c0ee9a60,3",
+    HyprctlEvents::MoveWindow {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        workspace_name: s("3")
+    }
+);
+fixture!(
+    movewindowv2,
+    "movewindowv2>>55d0c0ee9a60,3,3",
+    HyprctlEvents::MoveWindowV2 {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        workspace_id: WorkspaceId(3),
+        workspace_name: s("3")
+    }
+);
+fixture!(
+    openlayer,
+    "openlayer>>waybar",
+    HyprctlEvents::OpenLayer {
+        namespace: s("waybar")
+    }
+);
+fixture!(
+    closelayer,
+    "closelayer>>waybar",
+    HyprctlEvents::CloseLayer {
+        namespace: s("waybar")
+    }
+);
+fixture!(
+    submap,
+    "submap>>resize",
+    HyprctlEvents::Submap {
+        submap_name: s("resize")
+    }
+);
+fixture!(
+    changefloatingmode,
+    "changefloatingmode>>55d0c0ee9a60,1",
+    HyprctlEvents::ChangeFloatingMode {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        floating: s("1")
+    }
+);
+fixture!(
+    urgent,
+    "urgent>>55d0c0ee9a60",
+    HyprctlEvents::Urgent {
+        window_address: WindowAddress(0x55d0c0ee9a60)
+    }
+);
+fixture!(
+    minimized,
+    "minimized>>55d0c0ee9a60,1",
+    HyprctlEvents::Minimize {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        minimized: s("1")
+    }
+);
+fixture!(
+    minimize_legacy,
+    "minimize>>55d0c0ee9a60,0",
+    HyprctlEvents::Minimize {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        minimized: s("0")
+    }
+);
+fixture!(
+    screencast,
+    "screencast>>1,0",
+    HyprctlEvents::Screencast { state: 1, owner: 0 }
+);
+fixture!(
+    windowtitle,
+    "windowtitle>>55d0c0ee9a60",
+    HyprctlEvents::WindowTitle {
+        window_address: WindowAddress(0x55d0c0ee9a60)
+    }
+);
+fixture!(
+    windowtitlev2,
+    "windowtitlev2>>55d0c0ee9a60,~/src",
+    HyprctlEvents::WindowTitleV2 {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        window_title: s("~/src")
+    }
+);
+fixture!(
+    togglegroup,
+    "togglegroup>>1,55d0c0ee9a60,55d0c0f1b2c0",
+    HyprctlEvents::ToggleGroup {
+        state: 1,
+        handle: vec![WindowAddress(0x55d0c0ee9a60), WindowAddress(0x55d0c0f1b2c0)]
+    }
+);
+fixture!(
+    moveintogroup,
+    "moveintogroup>>55d0c0ee9a60",
+    HyprctlEvents::MoveIntoGroup {
+        window_address: WindowAddress(0x55d0c0ee9a60)
+    }
+);
+fixture!(
+    moveoutofgroup,
+    "moveoutofgroup>>55d0c0ee9a60",
+    HyprctlEvents::MoveOutOfGroup {
+        window_address: WindowAddress(0x55d0c0ee9a60)
+    }
+);
+fixture!(
+    ignoregrouplock,
+    "ignoregrouplock>>1",
+    HyprctlEvents::IgnoreGroupLock(1)
+);
+fixture!(lockgroups, "lockgroups>>0", HyprctlEvents::LockGroups(0));
+fixture!(
+    configreloaded,
+    "configreloaded>>",
+    HyprctlEvents::ConfigReloaded
+);
+fixture!(
+    pin,
+    "pin>>55d0c0ee9a60,1",
+    HyprctlEvents::Pin {
+        window_address: WindowAddress(0x55d0c0ee9a60),
+        pin_state: s("1")
+    }
+);
+fixture!(
+    bell,
+    "bell>>55d0c0ee9a60",
+    HyprctlEvents::Bell {
+        window_address: Some(WindowAddress(0x55d0c0ee9a60))
+    }
+);
+fixture!(
+    bell_without_window,
+    "bell>>",
+    HyprctlEvents::Bell {
+        window_address: None
+    }
+);
+
+#[test]
+fn line_without_separator_is_malformed() {
+    let decoded = HyprctlEvents::decode_from_string(s("workspace"));
+    assert!(matches!(decoded, Err(HyprError::MalformedEvent { .. })));
+}
+
+#[test]
+fn borrowed_fields_point_into_the_line() {
+    let line = "windowtitlev2>>55d0c0ee9a60,~/src";
+    let HyprctlEventRef::WindowTitleV2 {
+        window_address,
+        window_title,
+    } = HyprctlEventRef::decode(line).unwrap()
+    else {
+        panic!("not a windowtitlev2");
+    };
+
+    assert_eq!(window_address, WindowAddress(0x55d0c0ee9a60));
+    assert_eq!(window_title, "~/src");
+    assert!(line
+        .as_bytes()
+        .as_ptr_range()
+        .contains(&window_title.as_ptr()));
+}
+
+#[test]
+fn togglegroup_with_a_bad_address_is_malformed() {
+    let decoded = HyprctlEventRef::decode("togglegroup>>1,55d0c0ee9a60,nope");
+    assert!(matches!(decoded, Err(HyprError::MalformedEvent { .. })));
+}
+
+fixture!(
+    unknown,
+    "somethingnew>>1,a,b",
+    HyprctlEvents::Unknown {
+        name: s("somethingnew"),
+        args: s("1,a,b")
+    }
+);
@@ -0,0 +1,44 @@
+use hypr::{WindowAddress, WorkspaceId};
+
+#[test]
+fn window_address_parses_with_and_without_prefix() {
+    let address = WindowAddress(0x55d0c0ee9a60);
+
+    assert_eq!("55d0c0ee9a60".parse(), Ok(address));
+    assert_eq!("0x55d0c0ee9a60".parse(), Ok(address));
+    assert!("0x".parse::<WindowAddress>().is_err());
+    assert!("kitty".parse::<WindowAddress>().is_err());
+}
+
+#[test]
+fn window_address_formats_round_trip() {
+    let address = WindowAddress(0x55d0c0ee9a60);
+
+    assert_eq!(address.to_string(), "0x55d0c0ee9a60");
+    assert_eq!(format!("{:x}", address), "55d0c0ee9a60");
+    assert_eq!(address.to_string().parse(), Ok(address));
+    assert_eq!(format!("{:x}", address).parse(), Ok(address));
+}
+
+#[test]
+fn workspace_ids_classify_by_hyprland_range() {
+    // (id, regular, special)
+    let cases = [
+        (1, true, false),
+        // `special`, then `special:name` ones counting up
+        (-99, false, true),
+        (-98, false, true),
+        (-2, false, true),
+        // named workspaces count down from -1337
+        (-1337, false, false),
+        (-1338, false, false),
+        // no workspace
+        (-1, false, false),
+    ];
+
+    for (id, regular, special) in cases {
+        let id = WorkspaceId(id);
+        assert_eq!(id.is_regular(), regular, "{} is_regular", id);
+        assert_eq!(id.is_special(), special, "{} is_special", id);
+    }
+}
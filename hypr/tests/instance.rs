@@ -0,0 +1,34 @@
+use hypr::{testing::MockHyprland, Instance};
+
+#[tokio::test]
+async fn lists_live_instances_only() {
+    let mock = MockHyprland::start().await.unwrap();
+    // left behind by a crashed hyprland: the dir is there, nothing listens
+    std::fs::create_dir_all(format!("{}/hypr/stale_instance", mock.xdg_runtime_dir())).unwrap();
+
+    let instances = Instance::list_in(mock.xdg_runtime_dir()).await;
+
+    assert_eq!(instances.len(), 1);
+    assert_eq!(instances[0].signature, mock.instance_signature());
+    assert_eq!(instances[0].xdg_runtime_dir, mock.xdg_runtime_dir());
+}
+
+#[tokio::test]
+async fn missing_runtime_dir_has_no_instances() {
+    let instances = Instance::list_in("/nonexistent/runtime/dir").await;
+
+    assert!(instances.is_empty());
+}
+
+#[tokio::test]
+async fn connects_to_listed_instance() {
+    let mock = MockHyprland::start().await.unwrap();
+
+    let instance = Instance::list_in(mock.xdg_runtime_dir()).await.remove(0);
+    let hypr = instance.connect().await.unwrap();
+
+    assert_eq!(
+        hypr.controller().instance_signature(),
+        mock.instance_signature()
+    );
+}
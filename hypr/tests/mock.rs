@@ -0,0 +1,506 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+use futures::StreamExt;
+
+use hypr::{
+    controller::{
+        dispatch::{Dispatcher, WorkspaceSelector},
+        invoke::{
+            info::Info,
+            notify::{Color, Icon},
+            Method,
+        },
+        Controller,
+    },
+    events::{HyprctlEventKind, HyprctlEvents},
+    testing::{FakeControl, MockHyprland},
+    Hypr, HyprControl, HyprError, ReconnectPolicy, RecvError, WorkspaceId,
+};
+
+const WORKSPACES: &str = r#"[{
+    "id": 1,
+    "name": "1",
+    "monitor": "eDP-1",
+    "monitorID": 0,
+    "windows": 2,
+    "hasfullscreen": false,
+    "lastwindow": "0x55d0c0ee9a60",
+    "lastwindowtitle": "~/src"
+}]"#;
+
+async fn controller(mock: &MockHyprland) -> Controller {
+    Controller::new(mock.xdg_runtime_dir(), mock.instance_signature()).await
+}
+
+#[tokio::test]
+async fn controller_decodes_scripted_reply() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply("j/workspaces", WORKSPACES).await;
+
+    let workspaces = controller(&mock).await.get_workspaces().await.unwrap();
+
+    assert_eq!(workspaces.len(), 1);
+    assert_eq!(workspaces[0].monitor.as_str(), "eDP-1");
+    assert_eq!(workspaces[0].last_window_title, "~/src");
+    assert_eq!(mock.requests().await, vec!["j/workspaces"]);
+}
+
+#[tokio::test]
+async fn dispatch_maps_reply_to_result() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply("dispatch workspace 3", "ok").await;
+    let controller = controller(&mock).await;
+
+    controller
+        .dispatch(Dispatcher::Workspace(WorkspaceSelector::Id(WorkspaceId(3))))
+        .await
+        .unwrap();
+
+    let rejected = controller
+        .dispatch(Dispatcher::Workspace(WorkspaceSelector::Id(WorkspaceId(4))))
+        .await;
+    assert!(
+        matches!(rejected, Err(HyprError::HyprctlRejected { reply }) if reply == "unknown request")
+    );
+}
+
+#[tokio::test]
+async fn get_option_decodes_typed_values() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply(
+        "j/getoption general:border_size",
+        r#"{"option": "general:border_size", "int": 2, "set": true}"#,
+    )
+    .await;
+    mock.reply(
+        "j/getoption decoration:active_opacity",
+        r#"{"option": "decoration:active_opacity", "float": 0.9, "set": false}"#,
+    )
+    .await;
+    let controller = controller(&mock).await;
+
+    assert_eq!(
+        controller
+            .get_option::<i64>("general:border_size")
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        controller
+            .get_option::<f64>("decoration:active_opacity")
+            .await
+            .unwrap(),
+        0.9
+    );
+    assert!(matches!(
+        controller.get_option::<String>("general:border_size").await,
+        Err(HyprError::UnexpectedOptionType {
+            expected: "str",
+            ..
+        })
+    ));
+    assert!(matches!(
+        controller.get_option::<i64>("general:no_such_option").await,
+        Err(HyprError::HyprctlRejected { .. })
+    ));
+}
+
+#[tokio::test]
+async fn keyword_sets_option() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply("keyword general:gaps_in 0", "ok").await;
+
+    controller(&mock)
+        .await
+        .keyword("general:gaps_in", "0")
+        .await
+        .unwrap();
+
+    assert_eq!(mock.requests().await, vec!["keyword general:gaps_in 0"]);
+}
+
+#[tokio::test]
+async fn batch_sends_one_request() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply(
+        "[[BATCH]]dispatch workspace 3;keyword general:gaps_in 0;dispatch exit",
+        "ok\n\n\nok\n\n\nInvalid dispatcher\n\n\n",
+    )
+    .await;
+
+    let results = controller(&mock)
+        .await
+        .batch()
+        .dispatch(Dispatcher::Workspace(WorkspaceSelector::Id(WorkspaceId(3))))
+        .keyword("general:gaps_in", "0")
+        .dispatch(Dispatcher::Exit)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(
+        matches!(&results[2], Err(HyprError::HyprctlRejected { reply }) if reply == "Invalid dispatcher")
+    );
+    assert_eq!(mock.requests().await.len(), 1);
+}
+
+#[tokio::test]
+async fn notifier_formats_notify_requests() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply("notify 5 2500 rgb(ff1ea3) fontsize:20 build done", "ok")
+        .await;
+    mock.reply("dismissnotify -1", "ok").await;
+    mock.reply("dismissnotify 2", "ok").await;
+    let controller = controller(&mock).await;
+    let notifier = controller
+        .notifier()
+        .with_icon(Icon::Ok)
+        .with_duration(Duration::from_millis(2500))
+        .with_color("#ff1ea3".parse().unwrap())
+        .with_font_size(Some(20));
+
+    notifier.send("build done").await.unwrap();
+    notifier.dismiss_all().await.unwrap();
+    notifier.dismiss_recent(2).await.unwrap();
+}
+
+#[test]
+fn colors_parse_from_hex_and_hyprland_syntax() {
+    assert_eq!(
+        "#ff1ea3".parse::<Color>().unwrap(),
+        Color::Rgb(0xff, 0x1e, 0xa3)
+    );
+    assert_eq!(
+        "rgba(ff1ea3cc)".parse::<Color>().unwrap(),
+        Color::Rgba(0xff, 0x1e, 0xa3, 0xcc)
+    );
+    assert_eq!(
+        Color::Rgba(0xff, 0x1e, 0xa3, 0xcc).to_string(),
+        "rgba(ff1ea3cc)"
+    );
+    assert!("#ff1ea".parse::<Color>().is_err());
+    assert!("rgb(zzzzzz)".parse::<Color>().is_err());
+}
+
+#[tokio::test]
+async fn response_over_cap_is_rejected() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply("j/workspaces", WORKSPACES).await;
+
+    let workspaces = controller(&mock)
+        .await
+        .with_max_response_bytes(16)
+        .get_workspaces()
+        .await;
+
+    assert!(matches!(
+        workspaces,
+        Err(HyprError::ResponseTooLarge { .. })
+    ));
+}
+
+#[tokio::test]
+async fn stalled_hyprland_times_out() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.stall("j/workspaces").await;
+    mock.reply("j/activewindow", r#"{"class": "kitty", "title": "~/src"}"#)
+        .await;
+    let controller = controller(&mock)
+        .await
+        .with_response_timeout(Duration::from_millis(50));
+
+    let workspaces = controller.get_workspaces().await;
+    assert!(matches!(workspaces, Err(HyprError::Timeout)));
+
+    let reply = controller
+        .invoke_with_timeout(Method::Info(Info::Workspaces), Duration::from_millis(10))
+        .await;
+    assert!(matches!(reply, Err(HyprError::Timeout)));
+
+    controller.get_active_window().await.unwrap();
+
+    let metrics = controller.metrics();
+    assert_eq!(metrics.calls, 3);
+    assert_eq!(metrics.timeouts, 2);
+    assert_eq!(metrics.failures, 2);
+    assert!(metrics.max_latency >= Duration::from_millis(50));
+}
+
+#[tokio::test]
+async fn concurrent_calls_queue_for_a_socket() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.stall("j/workspaces").await;
+    let controller = controller(&mock)
+        .await
+        .with_max_concurrent_calls(NonZeroUsize::MIN)
+        .with_response_timeout(Duration::from_millis(100));
+
+    // the stalled call holds the only socket, so the second one never gets to ask
+    let (stalled, queued) = tokio::join!(
+        controller.get_workspaces(),
+        controller.invoke_with_timeout(Method::Info(Info::Version), Duration::from_millis(50)),
+    );
+
+    assert!(matches!(stalled, Err(HyprError::Timeout)));
+    assert!(matches!(queued, Err(HyprError::Timeout)));
+    assert_eq!(mock.requests().await, vec!["j/workspaces"]);
+}
+
+#[tokio::test]
+async fn next_decodes_pushed_events() {
+    let mock = MockHyprland::start().await.unwrap();
+    let mut hypr = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap();
+
+    mock.push_event("workspacev2>>2,2").await;
+    mock.push_event("activewindow>>kitty,~/src").await;
+
+    assert_eq!(
+        hypr.next().await.unwrap(),
+        HyprctlEvents::WorkspaceV2 {
+            workspace_id: WorkspaceId(2),
+            workspace_name: "2".to_string(),
+        }
+    );
+    assert_eq!(
+        hypr.next().await.unwrap(),
+        HyprctlEvents::ActiveWindow {
+            window_class: "kitty".to_string(),
+            window_title: "~/src".to_string(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn hypr_runs_over_any_control() {
+    let mock = MockHyprland::start().await.unwrap();
+    let fake = FakeControl::new();
+    fake.reply("dispatch exit", "ok");
+    let mut hypr = Hypr::with_control(mock.xdg_runtime_dir(), mock.instance_signature(), fake)
+        .await
+        .unwrap();
+
+    mock.push_event("workspace>>1").await;
+    hypr.next().await.unwrap();
+    hypr.controller().dispatch(Dispatcher::Exit).await.unwrap();
+
+    assert_eq!(
+        hypr.controller().requests(),
+        vec!["j/version", "dispatch exit"]
+    );
+    // only the event socket was used
+    assert!(mock.requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn next_reconnects_after_socket_closes() {
+    let mock = MockHyprland::start().await.unwrap();
+    let mut hypr = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap()
+        .with_reconnect_policy(Some(ReconnectPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(10),
+            max_attempts: Some(10),
+        }));
+
+    mock.push_event("workspace>>1").await;
+    hypr.next().await.unwrap();
+
+    mock.disconnect_listeners().await;
+    assert_eq!(hypr.next().await.unwrap(), HyprctlEvents::Reconnected);
+
+    mock.push_event("workspace>>2").await;
+    assert_eq!(
+        hypr.next().await.unwrap(),
+        HyprctlEvents::Workspace {
+            workspace_name: "2".to_string(),
+        }
+    );
+}
+
+// two hyprlands under one runtime dir, attached to the older one
+async fn attached_to_older_of_two() -> (MockHyprland, MockHyprland, Hypr) {
+    let older = MockHyprland::start().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let newer = older.start_sibling("newer_instance").await.unwrap();
+
+    let hypr = Hypr::new(older.xdg_runtime_dir(), older.instance_signature())
+        .await
+        .unwrap()
+        .with_reconnect_policy(Some(ReconnectPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(10),
+            max_attempts: Some(10),
+        }));
+
+    (older, newer, hypr)
+}
+
+#[tokio::test]
+async fn reconnect_stays_on_the_same_instance() {
+    let (older, newer, mut hypr) = attached_to_older_of_two().await;
+
+    older.disconnect_listeners().await;
+    assert_eq!(hypr.next().await.unwrap(), HyprctlEvents::Reconnected);
+
+    newer.push_event("workspace>>9").await;
+    older.push_event("workspace>>2").await;
+    assert_eq!(
+        hypr.next().await.unwrap(),
+        HyprctlEvents::Workspace {
+            workspace_name: "2".to_string(),
+        }
+    );
+    assert_eq!(
+        hypr.controller().instance_signature(),
+        older.instance_signature()
+    );
+}
+
+#[tokio::test]
+async fn reconnect_moves_on_once_the_instance_is_gone() {
+    let (older, newer, mut hypr) = attached_to_older_of_two().await;
+
+    drop(older);
+    assert_eq!(hypr.next().await.unwrap(), HyprctlEvents::Reconnected);
+
+    newer.push_event("workspace>>9").await;
+    assert_eq!(
+        hypr.next().await.unwrap(),
+        HyprctlEvents::Workspace {
+            workspace_name: "9".to_string(),
+        }
+    );
+    assert_eq!(
+        hypr.controller().instance_signature(),
+        newer.instance_signature()
+    );
+}
+
+#[tokio::test]
+async fn next_fails_on_close_without_reconnect_policy() {
+    let mock = MockHyprland::start().await.unwrap();
+    let mut hypr = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap()
+        .with_reconnect_policy(None);
+
+    mock.push_event("workspace>>1").await;
+    hypr.next().await.unwrap();
+
+    mock.disconnect_listeners().await;
+    assert!(matches!(hypr.next().await, Err(HyprError::Io(_))));
+}
+
+#[tokio::test]
+async fn stream_ends_on_close_without_reconnect_policy() {
+    let mock = MockHyprland::start().await.unwrap();
+    let mut events = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap()
+        .with_reconnect_policy(None)
+        .into_stream();
+
+    mock.push_event("workspace>>1").await;
+    mock.push_event("bogus").await;
+    mock.push_event("workspace>>2").await;
+
+    assert!(events.next().await.unwrap().is_ok());
+    // a bad line doesn't end the stream
+    assert!(matches!(
+        events.next().await.unwrap(),
+        Err(HyprError::MalformedEvent { .. })
+    ));
+    assert!(events.next().await.unwrap().is_ok());
+
+    mock.disconnect_listeners().await;
+    assert!(matches!(
+        events.next().await.unwrap(),
+        Err(HyprError::Io(_))
+    ));
+    assert!(events.next().await.is_none());
+}
+
+#[tokio::test]
+async fn hub_fans_out_to_filtered_subscribers() {
+    let mock = MockHyprland::start().await.unwrap();
+    let hub = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap()
+        .into_hub(16);
+
+    let mut everything = hub.subscribe();
+    let mut windows = hub.subscribe_to(&[HyprctlEventKind::ActiveWindow]);
+
+    mock.push_event("workspace>>1").await;
+    mock.push_event("activewindow>>kitty,~/src").await;
+
+    assert!(matches!(
+        everything.recv().await.unwrap(),
+        HyprctlEvents::Workspace { .. }
+    ));
+    assert!(matches!(
+        everything.recv().await.unwrap(),
+        HyprctlEvents::ActiveWindow { .. }
+    ));
+    assert!(matches!(
+        windows.recv().await.unwrap(),
+        HyprctlEvents::ActiveWindow { .. }
+    ));
+}
+
+#[tokio::test]
+async fn hub_survives_a_line_that_is_not_utf8() {
+    let mock = MockHyprland::start().await.unwrap();
+    let hub = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap()
+        .into_hub(16);
+    let mut events = hub.subscribe();
+
+    mock.push_raw_event(b"windowtitlev2>>55d0c0ee9a60,caf\xe9")
+        .await;
+    mock.push_event("workspace>>1").await;
+
+    assert_eq!(
+        events.recv().await.unwrap(),
+        HyprctlEvents::Workspace {
+            workspace_name: "1".to_string(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn slow_subscriber_is_told_how_many_events_it_missed() {
+    let mock = MockHyprland::start().await.unwrap();
+    let hub = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap()
+        .into_hub(2);
+    let mut slow = hub.subscribe();
+    let mut pacer = hub.subscribe();
+
+    for workspace in 1..=5 {
+        mock.push_event(&format!("workspace>>{}", workspace)).await;
+    }
+
+    // wait until the hub has broadcast everything
+    let last = HyprctlEvents::Workspace {
+        workspace_name: "5".to_string(),
+    };
+    while pacer.recv().await.ok() != Some(last.clone()) {}
+
+    assert!(matches!(slow.recv().await, Err(RecvError::Lagged(3))));
+    assert_eq!(
+        slow.recv().await.unwrap(),
+        HyprctlEvents::Workspace {
+            workspace_name: "4".to_string(),
+        }
+    );
+}
@@ -0,0 +1,351 @@
+use hypr::{
+    controller::{dispatch::WindowSelector, Controller},
+    testing::MockHyprland,
+    Capabilities, HyprlandVersion, MonitorName, WindowAddress, WorkspaceId,
+};
+
+// scripts `reply` for `request` and hands back a controller pointed at the mock
+async fn replying(request: &str, reply: &str) -> (MockHyprland, Controller) {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply(request, reply).await;
+    let controller = Controller::new(mock.xdg_runtime_dir(), mock.instance_signature()).await;
+
+    (mock, controller)
+}
+
+#[tokio::test]
+async fn monitors() {
+    let (_mock, controller) = replying("j/monitors", include_str!("fixtures/monitors.json")).await;
+
+    let monitors = controller.get_monitors().await.unwrap();
+
+    assert_eq!(monitors.len(), 2);
+    let laptop = &monitors[0];
+    assert_eq!(laptop.name, MonitorName::from("eDP-1"));
+    assert_eq!(laptop.active_workspace.id, WorkspaceId(2));
+    // nothing special shown
+    assert_eq!(laptop.special_workspace.id, WorkspaceId(0));
+    assert_eq!(laptop.special_workspace.name, "");
+    assert_eq!(laptop.reserved, [0, 30, 0, 0]);
+    assert_eq!(laptop.current_format, "XRGB2101010");
+    assert_eq!(laptop.mirror_of, "none");
+    assert_eq!(
+        laptop.available_modes,
+        [
+            "2560x1600@165.00Hz",
+            "2560x1600@60.00Hz",
+            "1920x1200@165.00Hz"
+        ]
+    );
+    assert_eq!(monitors[1].special_workspace.id, WorkspaceId(-98));
+    assert_eq!(monitors[1].special_workspace.name, "special:scratch");
+}
+
+#[tokio::test]
+async fn monitors_from_older_hyprland() {
+    let (_mock, controller) =
+        replying("j/monitors", include_str!("fixtures/monitors_legacy.json")).await;
+
+    let monitors = controller.get_monitors().await.unwrap();
+
+    assert!(!monitors[0].disabled);
+    assert_eq!(monitors[0].current_format, "");
+    assert_eq!(monitors[0].mirror_of, "");
+    assert!(monitors[0].available_modes.is_empty());
+}
+
+#[tokio::test]
+async fn clients() {
+    let (_mock, controller) = replying("j/clients", include_str!("fixtures/clients.json")).await;
+
+    let clients = controller.get_clients().await.unwrap();
+
+    assert_eq!(clients.len(), 3);
+    assert_eq!(clients[0].address, WindowAddress(0x55d0c0ee9a60));
+    assert_eq!(clients[0].fullscreen, 0);
+    assert_eq!(clients[0].swallowing, WindowAddress(0));
+    assert_eq!(clients[0].focus_history_id, Some(0));
+    // maximized and fullscreen
+    assert_eq!(clients[1].fullscreen, 3);
+    assert_eq!(
+        clients[1].grouped,
+        [WindowAddress(0x55d0c0f1b2c0), WindowAddress(0x55d0c0f1c3d0)]
+    );
+    assert_eq!(clients[1].tags, ["browser"]);
+    assert_eq!(clients[2].swallowing, WindowAddress(0x55d0c0ee9a60));
+}
+
+#[tokio::test]
+async fn clients_from_older_hyprland() {
+    let (_mock, controller) =
+        replying("j/clients", include_str!("fixtures/clients_legacy.json")).await;
+
+    let clients = controller.get_clients().await.unwrap();
+
+    // a bare `true` is plain fullscreen
+    assert_eq!(clients[0].fullscreen, 2);
+    assert_eq!(clients[0].focus_history_id, None);
+    assert!(clients[0].tags.is_empty());
+}
+
+#[tokio::test]
+async fn client_by_address() {
+    let (_mock, controller) = replying("j/clients", include_str!("fixtures/clients.json")).await;
+
+    let client = controller
+        .get_client_by_address(WindowAddress(0x55d0c0f1b2c0))
+        .await
+        .unwrap();
+    assert_eq!(client.unwrap().title, "Mozilla Firefox");
+
+    let missing = controller
+        .get_client_by_address(WindowAddress(0xdead))
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn clients_by_class() {
+    let (_mock, controller) = replying("j/clients", include_str!("fixtures/clients.json")).await;
+
+    let firefox = controller.get_clients_by_class("firefox").await.unwrap();
+
+    assert_eq!(
+        firefox.iter().map(|c| c.address).collect::<Vec<_>>(),
+        [WindowAddress(0x55d0c0f1b2c0), WindowAddress(0x55d0c0f1c3d0)]
+    );
+    assert!(controller
+        .get_clients_by_class("Firefox")
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn clients_on_workspace() {
+    let (_mock, controller) = replying("j/clients", include_str!("fixtures/clients.json")).await;
+
+    let on_two = controller
+        .get_clients_on_workspace(WorkspaceId(2))
+        .await
+        .unwrap();
+
+    assert_eq!(on_two.len(), 1);
+    assert_eq!(on_two[0].class, "kitty");
+    assert!(controller
+        .get_clients_on_workspace(WorkspaceId(9))
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn version() {
+    let (_mock, controller) = replying("j/version", include_str!("fixtures/version.json")).await;
+
+    let version = controller.get_version().await.unwrap();
+
+    assert_eq!(version.tag, "v0.41.2-12-g3d3b8a1d");
+    assert_eq!(version.version.as_deref(), Some("0.41.2"));
+    assert_eq!(version.flags, vec!["debug", "no xwayland"]);
+}
+
+#[tokio::test]
+async fn devices() {
+    let (_mock, controller) = replying("j/devices", include_str!("fixtures/devices.json")).await;
+
+    let devices = controller.get_devices().await.unwrap();
+
+    assert_eq!(devices.mice[0].name, "logitech-g-pro-x-superlight");
+    assert_eq!(devices.keyboards[0].active_keymap, "English (US)");
+    assert!(devices.keyboards[0].main);
+    assert_eq!(devices.tablets.len(), 3);
+    assert_eq!(devices.tablets[0].kind.as_deref(), Some("tabletPad"));
+    assert_eq!(
+        devices.tablets[0].belongs_to.as_ref().unwrap().name,
+        "wacom-intuos-pt-m-pen"
+    );
+    assert_eq!(devices.tablets[2].kind, None);
+    assert_eq!(devices.touch[0].name, "elan-touchscreen");
+    assert_eq!(devices.switches[0].name, "Lid Switch");
+}
+
+#[tokio::test]
+async fn binds() {
+    let (_mock, controller) = replying("j/binds", include_str!("fixtures/binds.json")).await;
+
+    let binds = controller.get_binds().await.unwrap();
+
+    assert_eq!(binds.len(), 2);
+    assert_eq!(binds[0].modmask, 64);
+    assert_eq!(binds[0].key, "Return");
+    assert_eq!(binds[0].dispatcher, "exec");
+    assert_eq!(binds[0].arg, "kitty");
+    assert_eq!(binds[1].submap, "resize");
+    assert_eq!(binds[1].description, "");
+}
+
+#[tokio::test]
+async fn layers() {
+    let (_mock, controller) = replying("j/layers", include_str!("fixtures/layers.json")).await;
+
+    let layers = controller.get_layers().await.unwrap();
+
+    let levels = &layers[&MonitorName::from("eDP-1")].levels;
+    assert_eq!(levels["0"][0].namespace, "hyprpaper");
+    assert_eq!(levels["2"][0].h, 32);
+    assert!(levels["3"].is_empty());
+}
+
+#[tokio::test]
+async fn cursor_pos() {
+    let (_mock, controller) = replying("j/cursorpos", r#"{"x": 1280, "y": -20}"#).await;
+
+    let cursor_pos = controller.get_cursor_pos().await.unwrap();
+
+    assert_eq!((cursor_pos.x, cursor_pos.y), (1280, -20));
+}
+
+#[tokio::test]
+async fn animations() {
+    let (_mock, controller) =
+        replying("j/animations", include_str!("fixtures/animations.json")).await;
+
+    let animations = controller.get_animations().await.unwrap();
+
+    assert_eq!(animations.animations.len(), 2);
+    assert_eq!(animations.animations[1].style, "popin 80%");
+    assert_eq!(animations.beziers[1].name, "overshot");
+}
+
+#[tokio::test]
+async fn instances() {
+    let (_mock, controller) =
+        replying("j/instances", include_str!("fixtures/instances.json")).await;
+
+    let instances = controller.get_instances().await.unwrap();
+
+    assert_eq!(instances[0].pid, 1499);
+    assert_eq!(instances[0].wl_socket, "wayland-1");
+}
+
+#[tokio::test]
+async fn layouts() {
+    let (_mock, controller) = replying("j/layouts", r#"["dwindle","master"]"#).await;
+
+    assert_eq!(
+        controller.get_layouts().await.unwrap(),
+        vec!["dwindle", "master"]
+    );
+}
+
+#[tokio::test]
+async fn config_errors() {
+    let (mock, controller) = replying("j/configerrors", r#"[""]"#).await;
+    assert!(controller.get_config_errors().await.unwrap().is_empty());
+
+    mock.reply(
+        "j/configerrors",
+        r#"["Config error in file ~/.config/hypr/hyprland.conf at line 12: invalid field"]"#,
+    )
+    .await;
+    assert_eq!(controller.get_config_errors().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn workspace_rules() {
+    let (_mock, controller) = replying(
+        "j/workspacerules",
+        include_str!("fixtures/workspacerules.json"),
+    )
+    .await;
+
+    let rules = controller.get_workspace_rules().await.unwrap();
+
+    assert_eq!(rules[0].monitor, Some(MonitorName::from("eDP-1")));
+    assert_eq!(rules[0].gaps_in, None);
+    assert_eq!(rules[1].gaps_out, Some([40, 40, 40, 40]));
+    assert_eq!(rules[1].on_created_empty.as_deref(), Some("kitty"));
+}
+
+#[tokio::test]
+async fn decorations() {
+    let (_mock, controller) = replying(
+        "j/decorations class:kitty",
+        include_str!("fixtures/decorations.json"),
+    )
+    .await;
+
+    let decorations = controller
+        .get_decorations(WindowSelector::Class("kitty"))
+        .await
+        .unwrap();
+
+    assert_eq!(decorations[0].decoration_name, "Drop Shadow");
+    assert_eq!(decorations[1].priority, 10000);
+}
+
+#[tokio::test]
+async fn splash() {
+    let (_mock, controller) = replying("j/splash", "Thanks Brodie!\n").await;
+
+    assert_eq!(controller.get_splash().await.unwrap(), "Thanks Brodie!");
+}
+
+#[tokio::test]
+async fn rolling_log() {
+    let (_mock, controller) = replying(
+        "j/rollinglog",
+        "[\n\"log\":\"[LOG] one\\n[LOG] \\\"two\\\"\"]",
+    )
+    .await;
+
+    assert_eq!(
+        controller.get_rolling_log().await.unwrap(),
+        "[LOG] one\n[LOG] \"two\""
+    );
+}
+
+#[tokio::test]
+async fn locked() {
+    let (_mock, controller) = replying("j/locked", r#"{"locked": true}"#).await;
+
+    assert!(controller.get_locked().await.unwrap());
+}
+
+#[tokio::test]
+async fn capabilities_follow_version() {
+    let (_mock, controller) = replying("j/version", include_str!("fixtures/version.json")).await;
+
+    let capabilities = controller.get_capabilities().await.unwrap();
+
+    assert_eq!(capabilities.version, Some(HyprlandVersion::new(0, 41, 2)));
+    assert!(capabilities.workspace_v2_event);
+    assert!(capabilities.workspace_lifecycle_v2_events);
+    assert!(capabilities.focus_history_id);
+}
+
+#[test]
+fn versions_parse_from_release_and_tag() {
+    assert_eq!("0.41.2".parse(), Ok(HyprlandVersion::new(0, 41, 2)));
+    assert_eq!(
+        "v0.33.1-12-g3d3b8a1d".parse(),
+        Ok(HyprlandVersion::new(0, 33, 1))
+    );
+    assert!("main".parse::<HyprlandVersion>().is_err());
+
+    let old = Capabilities::for_version(HyprlandVersion::new(0, 33, 1));
+    assert!(!old.workspace_v2_event);
+    assert!(!old.workspace_lifecycle_v2_events);
+    assert!(!old.focus_history_id);
+
+    // workspacev2 shipped several releases after the other workspace v2 events
+    let between = Capabilities::for_version(HyprlandVersion::new(0, 36, 0));
+    assert!(!between.workspace_v2_event);
+    assert!(between.workspace_lifecycle_v2_events);
+    assert!(between.focus_history_id);
+
+    let new = Capabilities::for_version(HyprlandVersion::new(0, 38, 0));
+    assert!(new.workspace_v2_event);
+}
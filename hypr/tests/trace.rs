@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use hypr::{events::HyprctlEvents, testing::MockHyprland, Hypr, Recorder, Replay};
+use tokio::time::{self, Instant};
+
+// three lines, recorded 1s and then 2s apart
+const TRACE: &str = "0\tworkspace>>1\n1000000\tworkspace>>2\n3000000\tworkspace>>3\n";
+
+// when each line came out of the replay, relative to the first
+async fn replay_offsets(speed: f64) -> Vec<Duration> {
+    let trace = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(trace.path(), TRACE).unwrap();
+    let mut replay = Replay::open(trace.path(), speed).await.unwrap();
+
+    let started = Instant::now();
+    let mut offsets = Vec::new();
+    while replay.next_line().await.unwrap().is_some() {
+        offsets.push(started.elapsed());
+    }
+
+    offsets
+}
+
+#[tokio::test]
+async fn recorded_events_replay_in_order() {
+    let mock = MockHyprland::start().await.unwrap();
+    let trace = tempfile::NamedTempFile::new().unwrap();
+
+    let mut hypr = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap()
+        .with_recorder(Recorder::create(trace.path()).await.unwrap());
+
+    let lines = ["workspacev2>>2,2", "activewindow>>firefox,Inbox, 3 unread"];
+    let mut live = Vec::new();
+    for line in lines {
+        mock.push_event(line).await;
+        live.push(hypr.next().await.unwrap());
+    }
+
+    let replayed: Vec<HyprctlEvents> = Replay::open(trace.path(), 0.0)
+        .await
+        .unwrap()
+        .into_stream()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(replayed, live);
+}
+
+#[tokio::test]
+async fn failing_trace_does_not_stop_events() {
+    let mock = MockHyprland::start().await.unwrap();
+    // every write fails with ENOSPC, like a full disk
+    let mut hypr = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap()
+        .with_recorder(Recorder::create("/dev/full").await.unwrap());
+
+    mock.push_event("workspace>>1").await;
+    mock.push_event("workspace>>2").await;
+
+    assert_eq!(
+        hypr.next().await.unwrap(),
+        HyprctlEvents::Workspace {
+            workspace_name: "1".to_string(),
+        }
+    );
+    assert_eq!(
+        hypr.next().await.unwrap(),
+        HyprctlEvents::Workspace {
+            workspace_name: "2".to_string(),
+        }
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn replay_keeps_recorded_delays() {
+    assert_eq!(
+        replay_offsets(1.0).await,
+        [0, 1000, 3000].map(Duration::from_millis)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn replay_speed_scales_recorded_delays() {
+    assert_eq!(
+        replay_offsets(2.0).await,
+        [0, 500, 1500].map(Duration::from_millis)
+    );
+    assert_eq!(replay_offsets(0.0).await, [Duration::ZERO; 3]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn replay_at_a_tiny_speed_does_not_overflow() {
+    let trace = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(trace.path(), "0\tworkspace>>1\n3600000000\tworkspace>>2\n").unwrap();
+    let mut replay = Replay::open(trace.path(), 1e-20).await.unwrap();
+
+    assert!(replay.next_line().await.unwrap().is_some());
+    // due so far out that it never comes
+    assert!(time::timeout(Duration::from_secs(3600), replay.next_line())
+        .await
+        .is_err());
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use hypr::controller::invoke::notify::{Color, Icon};
+
+#[derive(Parser)]
+#[command(about = "hyprland state as a stream of json lines")]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// hyprland instance to attach to, by signature (prefix) or index;
+    /// defaults to $HYPRLAND_INSTANCE_SIGNATURE, then the newest running instance
+    #[arg(long, short, value_name = "INSTANCE", conflicts_with = "replay")]
+    pub instance: Option<String>,
+
+    /// write every raw hyprland event into this file, for bug reports
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// feed a recorded trace through the state loop instead of a live hyprland
+    #[arg(long, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// playback speed for --replay; 0 replays as fast as possible
+    #[arg(
+        long,
+        value_name = "FACTOR",
+        default_value_t = 1.0,
+        requires = "replay"
+    )]
+    pub replay_speed: f64,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// raise a hyprland notification and exit
+    Notify {
+        message: String,
+
+        #[arg(long, value_enum, default_value_t = NotifyIcon::None)]
+        icon: NotifyIcon,
+
+        /// how long the notification stays up
+        #[arg(long, value_name = "MS", default_value_t = 5000)]
+        duration_ms: u64,
+
+        /// #rrggbb, #rrggbbaa, rgb(rrggbb) or rgba(rrggbbaa)
+        #[arg(long)]
+        color: Option<Color>,
+
+        #[arg(long)]
+        font_size: Option<u32>,
+    },
+
+    /// dismiss hyprland notifications and exit
+    Dismiss {
+        /// how many to dismiss, oldest first; all of them if omitted
+        count: Option<u32>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum NotifyIcon {
+    None,
+    Warning,
+    Info,
+    Hint,
+    Error,
+    Confused,
+    Ok,
+}
+
+impl From<NotifyIcon> for Icon {
+    fn from(icon: NotifyIcon) -> Self {
+        match icon {
+            NotifyIcon::None => Icon::NoIcon,
+            NotifyIcon::Warning => Icon::Warning,
+            NotifyIcon::Info => Icon::Info,
+            NotifyIcon::Hint => Icon::Hint,
+            NotifyIcon::Error => Icon::Error,
+            NotifyIcon::Confused => Icon::Confused,
+            NotifyIcon::Ok => Icon::Ok,
+        }
+    }
+}
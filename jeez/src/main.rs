@@ -1,8 +1,11 @@
-mod constants;
+mod args;
+use clap::Parser;
+use futures::StreamExt;
+use hypr::{controller::Controller, events::HyprctlEvents, Instance, Recorder, Replay};
 use log::{debug, warn};
 use serde_json::json;
 use state::StateUpdate;
-use tokio::sync::mpsc;
+use std::{pin::pin, time::Duration};
 
 macro_rules! continue_on_err {
     ($predicate:expr, $err_patt:expr) => {
@@ -18,50 +21,54 @@ macro_rules! continue_on_err {
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let constants = constants::Constants::new();
+    let args = args::Args::parse();
 
-    let mut hypr = hypr::Hypr::new(
-        &constants.xdg_runtime_dir,
-        &constants.hyprland_instance_signature,
-    )
-    .await;
+    if let Some(command) = args.command {
+        let instance = find_instance(args.instance.as_deref()).await?;
+        let controller = Controller::new(&instance.xdg_runtime_dir, &instance.signature).await;
+        return run_command(&controller, command).await;
+    }
+
+    let (mut state, events, controller) = match &args.replay {
+        // nothing to bootstrap from; the trace is all there is
+        Some(trace) => {
+            let replay = Replay::open(trace, args.replay_speed).await?;
+            (state::State::default(), replay.into_stream(), None)
+        }
+        None => {
+            let instance = find_instance(args.instance.as_deref()).await?;
 
-    // initialize global state with some default values
-    let initial_total_workspaces =
-        (1..(hypr.controller().get_workspaces().await?.len() + 1) as i32).collect();
-    let initial_current_workspace = hypr.controller().get_active_workspace().await?.id as i32;
-    let initial_current_app_name = hypr.controller().get_active_window().await?;
-    let initial_current_app_name = format!(
-        "{} / {}",
-        initial_current_app_name.class, initial_current_app_name.title
-    );
+            let mut hypr = instance.connect().await?;
+            if let Some(trace) = &args.record {
+                hypr = hypr.with_recorder(Recorder::create(trace).await?);
+            }
+            let controller = hypr.controller().clone();
 
-    let mut state = state::State {
-        total_workspaces: initial_total_workspaces,
-        current_workspace: initial_current_workspace,
-        current_app_name: initial_current_app_name,
-        current_volume: Default::default(),
-        current_brightness: Default::default(),
+            // initialize global state with some default values
+            let state = state::State::bootstrap(&controller).await?;
+            (state, hypr.into_stream(), Some(controller))
+        }
     };
 
     // print initial state
     println!("{}", json!(state));
 
-    let (tx, mut rx) = mpsc::channel::<state::Events>(1024);
-
-    tokio::spawn(async move {
-        let tx = tx.clone();
-        loop {
-            let next_event = continue_on_err!(
-                hypr.next().await,
-                "received hypr events but could not decode"
-            );
-
-            tx.send(state::Events::Hypr(next_event)).await.unwrap();
+    let events = events.then(|next_event| {
+        let controller = controller.clone();
+        async move {
+            anyhow::Ok(match (next_event?, controller) {
+                // whatever happened while disconnected is lost; start over from the controller
+                (HyprctlEvents::Reconnected, Some(controller)) => {
+                    state::Events::Resync(state::State::bootstrap(&controller).await?)
+                }
+                (next_event, _) => state::Events::Hypr(next_event),
+            })
         }
     });
+    let mut events = pin!(events);
 
-    while let Some(event) = rx.recv().await {
+    while let Some(event) = events.next().await {
+        let event = continue_on_err!(event, "received hypr events but could not decode");
         let state_update = continue_on_err!(state.update_from_event(event), "state update failed");
 
         match state_update {
@@ -78,3 +85,36 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+async fn find_instance(selector: Option<&str>) -> anyhow::Result<Instance> {
+    Ok(match selector {
+        Some(selector) => Instance::select(selector).await?,
+        None => Instance::discover().await?,
+    })
+}
+
+async fn run_command(controller: &Controller, command: args::Command) -> anyhow::Result<()> {
+    let notifier = controller.notifier();
+
+    match command {
+        args::Command::Notify {
+            message,
+            icon,
+            duration_ms,
+            color,
+            font_size,
+        } => {
+            notifier
+                .with_icon(icon.into())
+                .with_duration(Duration::from_millis(duration_ms))
+                .with_color(color.unwrap_or_default())
+                .with_font_size(font_size)
+                .send(&message)
+                .await?
+        }
+        args::Command::Dismiss { count: Some(count) } => notifier.dismiss_recent(count).await?,
+        args::Command::Dismiss { count: None } => notifier.dismiss_all().await?,
+    }
+
+    Ok(())
+}
@@ -1,5 +1,10 @@
 use hypr::events::HyprctlEvents;
 
+use crate::State;
+
 pub enum Events {
     Hypr(HyprctlEvents),
+
+    // fresh snapshot taken from the controller, e.g. after a reconnect
+    Resync(State),
 }
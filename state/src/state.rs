@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use hypr::events::HyprctlEvents;
+use hypr::{events::HyprctlEvents, Capabilities, HyprControl, WorkspaceId};
 use log::info;
 use serde::{Deserialize, Serialize};
 
@@ -8,12 +8,16 @@ use crate::Events;
 
 #[derive(Serialize, Deserialize, Default)]
 pub struct State {
-    pub total_workspaces: BTreeSet<i32>,
-    pub current_workspace: i32,
+    pub total_workspaces: BTreeSet<WorkspaceId>,
+    pub current_workspace: WorkspaceId,
     pub current_app_name: String,
 
     pub current_volume: u32,
     pub current_brightness: u32,
+
+    // decides which of the v1/v2 event pairs we follow
+    #[serde(skip)]
+    pub capabilities: Capabilities,
 }
 
 pub enum StateUpdate {
@@ -22,11 +26,35 @@ pub enum StateUpdate {
 }
 
 impl State {
+    // a fresh snapshot straight from the controller, for startup and after reconnects
+    pub async fn bootstrap(controller: &impl HyprControl) -> anyhow::Result<Self> {
+        // the ids hyprland reports; named and special workspaces aren't counted
+        let total_workspaces = controller
+            .get_workspaces()
+            .await?
+            .into_iter()
+            .map(|workspace| workspace.id)
+            .filter(WorkspaceId::is_regular)
+            .collect();
+        let current_workspace = controller.get_active_workspace().await?.id;
+        let active_window = controller.get_active_window().await?;
+        let current_app_name = format!("{} / {}", active_window.class, active_window.title);
+
+        Ok(State {
+            total_workspaces,
+            current_workspace,
+            current_app_name,
+            current_volume: Default::default(),
+            current_brightness: Default::default(),
+            capabilities: controller.get_capabilities().await.unwrap_or_default(),
+        })
+    }
+
     pub fn update_from_event(&mut self, event: Events) -> anyhow::Result<StateUpdate> {
         match event {
             Events::Hypr(event) => match event {
                 HyprctlEvents::WorkspaceV2 { workspace_id, .. } => {
-                    self.current_workspace = workspace_id.parse().unwrap();
+                    self.current_workspace = workspace_id;
                     Ok(StateUpdate::Updated)
                 }
                 HyprctlEvents::ActiveWindow { window_title, .. } => {
@@ -34,8 +62,7 @@ impl State {
                     Ok(StateUpdate::Updated)
                 }
                 HyprctlEvents::CreateWorkspaceV2 { workspace_id, .. } => {
-                    let workspace_id: i32 = workspace_id.parse().unwrap();
-                    if workspace_id > 0 {
+                    if workspace_id.is_regular() {
                         self.total_workspaces.insert(workspace_id);
                         return Ok(StateUpdate::Updated);
                     }
@@ -43,21 +70,76 @@ impl State {
                     Ok(StateUpdate::Nop)
                 }
                 HyprctlEvents::DestroyWorkspaceV2 { workspace_id, .. } => {
-                    let workspace_id: i32 = workspace_id.parse().unwrap();
                     self.total_workspaces.remove(&workspace_id);
 
                     Ok(StateUpdate::Updated)
                 }
                 HyprctlEvents::MoveWorkspaceV2 { workspace_id, .. } => {
-                    let next_workspace: i32 = workspace_id.parse().unwrap();
-                    self.current_workspace = next_workspace;
+                    self.current_workspace = workspace_id;
                     Ok(StateUpdate::Updated)
                 }
+                // hyprland before the v2 events only tells us names;
+                // regular workspaces are named after their id unless renamed
+                HyprctlEvents::Workspace { workspace_name }
+                    if !self.capabilities.workspace_v2_event =>
+                {
+                    Ok(self.focus_legacy_workspace(&workspace_name))
+                }
+                HyprctlEvents::MoveWorkspace { workspace_name, .. }
+                    if !self.capabilities.workspace_lifecycle_v2_events =>
+                {
+                    Ok(self.focus_legacy_workspace(&workspace_name))
+                }
+                HyprctlEvents::CreateWorkspace { workspace_name }
+                    if !self.capabilities.workspace_lifecycle_v2_events =>
+                {
+                    match legacy_workspace_id(&workspace_name) {
+                        Some(workspace_id) if workspace_id.is_regular() => {
+                            self.total_workspaces.insert(workspace_id);
+                            Ok(StateUpdate::Updated)
+                        }
+                        _ => Ok(StateUpdate::Nop),
+                    }
+                }
+                HyprctlEvents::DestroyWorkspace { workspace_name }
+                    if !self.capabilities.workspace_lifecycle_v2_events =>
+                {
+                    let Some(workspace_id) = legacy_workspace_id(&workspace_name) else {
+                        return Ok(StateUpdate::Nop);
+                    };
+
+                    self.total_workspaces.remove(&workspace_id);
+                    Ok(StateUpdate::Updated)
+                }
+                HyprctlEvents::Unknown { name, args } => {
+                    info!("?? event {:?} unknown to hypr, args {:?}", name, args);
+                    Ok(StateUpdate::Nop)
+                }
                 e => {
                     info!("?? not handling unknown state update {:?}", e);
                     Ok(StateUpdate::Nop)
                 }
             },
+            Events::Resync(snapshot) => {
+                self.total_workspaces = snapshot.total_workspaces;
+                self.current_workspace = snapshot.current_workspace;
+                self.current_app_name = snapshot.current_app_name;
+                self.capabilities = snapshot.capabilities;
+                Ok(StateUpdate::Updated)
+            }
         }
     }
+
+    fn focus_legacy_workspace(&mut self, workspace_name: &str) -> StateUpdate {
+        let Some(workspace_id) = legacy_workspace_id(workspace_name) else {
+            return StateUpdate::Nop;
+        };
+
+        self.current_workspace = workspace_id;
+        StateUpdate::Updated
+    }
+}
+
+fn legacy_workspace_id(workspace_name: &str) -> Option<WorkspaceId> {
+    workspace_name.parse().ok()
 }
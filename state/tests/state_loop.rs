@@ -0,0 +1,173 @@
+use hypr::{
+    events::HyprctlEvents,
+    testing::{FakeControl, MockHyprland},
+    Hypr, WorkspaceId,
+};
+use state::{Events, State, StateUpdate};
+
+#[tokio::test]
+async fn state_follows_workspace_events() {
+    let mock = MockHyprland::start().await.unwrap();
+    let mut hypr = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap();
+    let mut state = State::default();
+
+    for line in [
+        "createworkspacev2>>1,1",
+        "createworkspacev2>>2,2",
+        "workspacev2>>2,2",
+        "activewindow>>kitty,~/src",
+        "destroyworkspacev2>>1,1",
+    ] {
+        mock.push_event(line).await;
+        let event = hypr.next().await.unwrap();
+
+        assert!(matches!(
+            state.update_from_event(Events::Hypr(event)).unwrap(),
+            StateUpdate::Updated
+        ));
+    }
+
+    assert_eq!(
+        state.total_workspaces.into_iter().collect::<Vec<_>>(),
+        [WorkspaceId(2)]
+    );
+    assert_eq!(state.current_workspace, WorkspaceId(2));
+    assert_eq!(state.current_app_name, "~/src");
+}
+
+#[tokio::test]
+async fn state_falls_back_to_v1_events_on_old_hyprland() {
+    let mock = MockHyprland::start().await.unwrap();
+    mock.reply(
+        "j/version",
+        r#"{"branch": "", "commit": "", "dirty": false, "commit_message": "",
+            "tag": "v0.32.3", "commits": "0", "flags": []}"#,
+    )
+    .await;
+    let mut hypr = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap();
+    let mut state = State {
+        capabilities: hypr.capabilities(),
+        ..Default::default()
+    };
+    assert!(!state.capabilities.workspace_v2_event);
+    assert!(!state.capabilities.workspace_lifecycle_v2_events);
+
+    for line in [
+        "createworkspace>>1",
+        "createworkspace>>2",
+        "workspace>>2",
+        "destroyworkspace>>1",
+    ] {
+        mock.push_event(line).await;
+        let event = hypr.next().await.unwrap();
+
+        assert!(matches!(
+            state.update_from_event(Events::Hypr(event)).unwrap(),
+            StateUpdate::Updated
+        ));
+    }
+
+    assert_eq!(
+        state.total_workspaces.into_iter().collect::<Vec<_>>(),
+        [WorkspaceId(2)]
+    );
+    assert_eq!(state.current_workspace, WorkspaceId(2));
+}
+
+#[tokio::test]
+async fn state_mixes_event_versions_between_v2_releases() {
+    let mock = MockHyprland::start().await.unwrap();
+    // lifecycle v2 events, but no workspacev2 yet
+    mock.reply(
+        "j/version",
+        r#"{"branch": "", "commit": "", "dirty": false, "commit_message": "",
+            "tag": "v0.36.0", "commits": "0", "flags": []}"#,
+    )
+    .await;
+    let mut hypr = Hypr::new(mock.xdg_runtime_dir(), mock.instance_signature())
+        .await
+        .unwrap();
+    let mut state = State {
+        capabilities: hypr.capabilities(),
+        ..Default::default()
+    };
+    assert!(!state.capabilities.workspace_v2_event);
+    assert!(state.capabilities.workspace_lifecycle_v2_events);
+
+    // hyprland sends both the v1 and v2 form of each lifecycle event
+    for (line, expected) in [
+        ("createworkspace>>1", false),
+        ("createworkspacev2>>1,1", true),
+        ("createworkspace>>2", false),
+        ("createworkspacev2>>2,2", true),
+        ("workspace>>2", true),
+        ("destroyworkspace>>1", false),
+        ("destroyworkspacev2>>1,1", true),
+    ] {
+        mock.push_event(line).await;
+        let event = hypr.next().await.unwrap();
+
+        let update = state.update_from_event(Events::Hypr(event)).unwrap();
+        assert_eq!(matches!(update, StateUpdate::Updated), expected, "{}", line);
+    }
+
+    assert_eq!(
+        state.total_workspaces.into_iter().collect::<Vec<_>>(),
+        [WorkspaceId(2)]
+    );
+    assert_eq!(state.current_workspace, WorkspaceId(2));
+}
+
+#[tokio::test]
+async fn state_ignores_v1_events_when_v2_is_available() {
+    let mut state = State::default();
+
+    let update = state
+        .update_from_event(Events::Hypr(HyprctlEvents::Workspace {
+            workspace_name: "3".to_string(),
+        }))
+        .unwrap();
+
+    assert!(matches!(update, StateUpdate::Nop));
+    assert_eq!(state.current_workspace, WorkspaceId::default());
+}
+
+#[tokio::test]
+async fn bootstrap_reads_controller_snapshot() {
+    let controller = FakeControl::new();
+    controller.reply(
+        "j/workspaces",
+        r#"[
+            {"id": 1, "name": "1", "monitor": "eDP-1", "monitorID": 0, "windows": 1,
+             "hasfullscreen": false, "lastwindow": "0x0", "lastwindowtitle": ""},
+            {"id": 3, "name": "3", "monitor": "eDP-1", "monitorID": 0, "windows": 1,
+             "hasfullscreen": false, "lastwindow": "0x0", "lastwindowtitle": ""},
+            {"id": 7, "name": "7", "monitor": "eDP-1", "monitorID": 0, "windows": 1,
+             "hasfullscreen": false, "lastwindow": "0x0", "lastwindowtitle": ""},
+            {"id": -1337, "name": "mail", "monitor": "eDP-1", "monitorID": 0, "windows": 1,
+             "hasfullscreen": false, "lastwindow": "0x0", "lastwindowtitle": ""},
+            {"id": -98, "name": "special:scratch", "monitor": "eDP-1", "monitorID": 0,
+             "windows": 1, "hasfullscreen": false, "lastwindow": "0x0", "lastwindowtitle": ""}
+        ]"#,
+    );
+    controller.reply(
+        "j/activeworkspace",
+        r#"{"id": 3, "name": "3", "monitor": "eDP-1", "monitorID": 0, "windows": 1,
+            "hasfullscreen": false, "lastwindow": "0x55d0c0ee9a60", "lastwindowtitle": "~/src"}"#,
+    );
+    controller.reply("j/activewindow", r#"{"class": "kitty", "title": "~/src"}"#);
+
+    let state = State::bootstrap(&controller).await.unwrap();
+
+    assert_eq!(
+        state.total_workspaces.into_iter().collect::<Vec<_>>(),
+        [WorkspaceId(1), WorkspaceId(3), WorkspaceId(7)]
+    );
+    assert_eq!(state.current_workspace, WorkspaceId(3));
+    assert_eq!(state.current_app_name, "kitty / ~/src");
+    assert!(state.capabilities.workspace_v2_event);
+}